@@ -0,0 +1,408 @@
+use std::{cmp::Ordering, num::NonZeroUsize};
+
+use crate::{NonEmptySlice, NonEmptyVec};
+
+mod error {
+    use std::{collections::TryReserveError, error::Error, fmt, num::NonZeroUsize};
+
+    #[derive(Debug)]
+    pub enum CapacityError {
+        Overflow,
+        TryReserve(TryReserveError),
+    }
+
+    impl fmt::Display for CapacityError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                CapacityError::Overflow => write!(f, "capacity overflow"),
+                CapacityError::TryReserve(error) => fmt::Display::fmt(error, f),
+            }
+        }
+    }
+
+    impl Error for CapacityError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                CapacityError::Overflow => None,
+                CapacityError::TryReserve(error) => Some(error),
+            }
+        }
+    }
+
+    impl From<TryReserveError> for CapacityError {
+        fn from(error: TryReserveError) -> Self {
+            CapacityError::TryReserve(error)
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct OutOfBounds {
+        pub index: usize,
+        pub len: NonZeroUsize,
+    }
+
+    impl fmt::Display for OutOfBounds {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "index {} is out of bounds for length {}",
+                self.index, self.len
+            )
+        }
+    }
+
+    impl Error for OutOfBounds {}
+
+    #[derive(Debug)]
+    pub enum InsertError {
+        OutOfBounds(OutOfBounds),
+        Capacity(CapacityError),
+    }
+
+    impl fmt::Display for InsertError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                InsertError::OutOfBounds(error) => fmt::Display::fmt(error, f),
+                InsertError::Capacity(error) => fmt::Display::fmt(error, f),
+            }
+        }
+    }
+
+    impl Error for InsertError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                InsertError::OutOfBounds(error) => Some(error),
+                InsertError::Capacity(error) => Some(error),
+            }
+        }
+    }
+
+    impl From<OutOfBounds> for InsertError {
+        fn from(error: OutOfBounds) -> Self {
+            InsertError::OutOfBounds(error)
+        }
+    }
+
+    impl From<CapacityError> for InsertError {
+        fn from(error: CapacityError) -> Self {
+            InsertError::Capacity(error)
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum RemoveError {
+        OutOfBounds(OutOfBounds),
+        LastElement,
+    }
+
+    impl fmt::Display for RemoveError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                RemoveError::OutOfBounds(error) => fmt::Display::fmt(error, f),
+                RemoveError::LastElement => write!(f, "cannot remove the last element"),
+            }
+        }
+    }
+
+    impl Error for RemoveError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                RemoveError::OutOfBounds(error) => Some(error),
+                RemoveError::LastElement => None,
+            }
+        }
+    }
+
+    impl From<OutOfBounds> for RemoveError {
+        fn from(error: OutOfBounds) -> Self {
+            RemoveError::OutOfBounds(error)
+        }
+    }
+}
+
+pub use error::{CapacityError, InsertError, OutOfBounds, RemoveError};
+
+fn check_index<T>(slice: &NonEmptySlice<T>, index: usize) -> Result<(), OutOfBounds> {
+    let len = slice.non_zero_len();
+    if index < len.get() {
+        Ok(())
+    } else {
+        Err(OutOfBounds { index, len })
+    }
+}
+
+pub fn with_capacity<T>(first: T, capacity: usize) -> Result<NonEmptyVec<T>, CapacityError> {
+    let mut vec = NonEmptyVec::one(first);
+    vec.try_reserve_exact(capacity.saturating_sub(1))?;
+    Ok(vec)
+}
+
+pub fn reserve<T>(vec: &mut NonEmptyVec<T>, additional: usize) -> Result<(), CapacityError> {
+    Ok(vec.try_reserve(additional)?)
+}
+
+pub fn reserve_exact<T>(vec: &mut NonEmptyVec<T>, additional: usize) -> Result<(), CapacityError> {
+    Ok(vec.try_reserve_exact(additional)?)
+}
+
+pub fn push<T>(vec: &mut NonEmptyVec<T>, value: T) -> Result<(), CapacityError> {
+    vec.try_reserve(1)?;
+    vec.push(value);
+    Ok(())
+}
+
+pub fn extend_from_slice<T: Clone>(
+    vec: &mut NonEmptyVec<T>,
+    other: &[T],
+) -> Result<(), CapacityError> {
+    vec.try_reserve(other.len())?;
+    vec.extend_from_slice(other);
+    Ok(())
+}
+
+pub fn from_first_tail<T: Clone>(first: T, tail: &[T]) -> Result<NonEmptyVec<T>, CapacityError> {
+    let capacity = tail.len().checked_add(1).ok_or(CapacityError::Overflow)?;
+    let mut vec = with_capacity(first, capacity)?;
+    vec.extend_from_slice(tail);
+    Ok(vec)
+}
+
+pub fn from_init_last<T: Clone>(init: &[T], last: T) -> Result<NonEmptyVec<T>, CapacityError> {
+    let Some((first, tail)) = init.split_first() else {
+        return Ok(NonEmptyVec::one(last));
+    };
+    let mut vec = from_first_tail(first.clone(), tail)?;
+    push(&mut vec, last)?;
+    Ok(vec)
+}
+
+pub fn repeat<T: Copy>(
+    slice: &NonEmptySlice<T>,
+    n: NonZeroUsize,
+) -> Result<NonEmptyVec<T>, CapacityError> {
+    let len = slice.len();
+    let total = len.checked_mul(n.get()).ok_or(CapacityError::Overflow)?;
+    let mut vec = with_capacity(*slice.first(), total)?;
+    vec.extend_from_slice(slice.tail());
+    for _ in 1..n.get() {
+        vec.extend_from_slice(slice);
+    }
+    Ok(vec)
+}
+
+pub fn get<T>(slice: &NonEmptySlice<T>, index: usize) -> Result<&T, OutOfBounds> {
+    check_index(slice, index)?;
+    // SAFETY: `index` was checked to be in bounds.
+    Ok(unsafe { slice.get_unchecked(index) })
+}
+
+pub fn get_mut<T>(slice: &mut NonEmptySlice<T>, index: usize) -> Result<&mut T, OutOfBounds> {
+    check_index(slice, index)?;
+    // SAFETY: `index` was checked to be in bounds.
+    Ok(unsafe { slice.get_unchecked_mut(index) })
+}
+
+pub fn split_at<T>(
+    slice: &NonEmptySlice<T>,
+    mid: NonZeroUsize,
+) -> Result<(&NonEmptySlice<T>, &[T]), OutOfBounds> {
+    check_index(slice, mid.get() - 1)?;
+    Ok(slice.split_at(mid))
+}
+
+pub fn split_at_mut<T>(
+    slice: &mut NonEmptySlice<T>,
+    mid: NonZeroUsize,
+) -> Result<(&mut NonEmptySlice<T>, &mut [T]), OutOfBounds> {
+    check_index(slice, mid.get() - 1)?;
+    Ok(slice.split_at_mut(mid))
+}
+
+pub fn select_nth_unstable<T: Ord>(
+    slice: &mut NonEmptySlice<T>,
+    index: usize,
+) -> Result<(&mut [T], &mut T, &mut [T]), OutOfBounds> {
+    check_index(slice, index)?;
+    Ok(slice.select_nth_unstable(index))
+}
+
+pub fn select_nth_unstable_by<T, F>(
+    slice: &mut NonEmptySlice<T>,
+    index: usize,
+    compare: F,
+) -> Result<(&mut [T], &mut T, &mut [T]), OutOfBounds>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    check_index(slice, index)?;
+    Ok(slice.select_nth_unstable_by(index, compare))
+}
+
+pub fn select_nth_unstable_by_key<T, K, F>(
+    slice: &mut NonEmptySlice<T>,
+    index: usize,
+    f: F,
+) -> Result<(&mut [T], &mut T, &mut [T]), OutOfBounds>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    check_index(slice, index)?;
+    Ok(slice.select_nth_unstable_by_key(index, f))
+}
+
+pub fn insert<T>(vec: &mut NonEmptyVec<T>, index: usize, element: T) -> Result<(), InsertError> {
+    let len = vec.non_zero_len();
+    if index > len.get() {
+        return Err(OutOfBounds { index, len }.into());
+    }
+    reserve(vec, 1)?;
+    vec.insert(index, element);
+    Ok(())
+}
+
+pub fn remove<T>(vec: &mut NonEmptyVec<T>, index: usize) -> Result<T, RemoveError> {
+    check_index(vec, index)?;
+    if vec.tail().is_empty() {
+        return Err(RemoveError::LastElement);
+    }
+    // SAFETY: `index` is in bounds and there are at least two elements,
+    // so one remains after the removal.
+    Ok(unsafe { vec.as_mut_vec() }.remove(index))
+}
+
+pub fn swap_remove<T>(vec: &mut NonEmptyVec<T>, index: usize) -> Result<T, RemoveError> {
+    check_index(vec, index)?;
+    if vec.tail().is_empty() {
+        return Err(RemoveError::LastElement);
+    }
+    // SAFETY: See `remove`.
+    Ok(unsafe { vec.as_mut_vec() }.swap_remove(index))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::non_empty_vec;
+
+    #[test]
+    fn push_and_extend() -> Result<(), CapacityError> {
+        let mut vec = with_capacity(10, 4)?;
+        assert!(vec.capacity() >= 4);
+
+        push(&mut vec, 20)?;
+        extend_from_slice(&mut vec, &[30, 40])?;
+        assert_eq!(vec, non_empty_vec![10, 20, 30, 40]);
+
+        assert!(matches!(
+            reserve(&mut vec, usize::MAX),
+            Err(CapacityError::TryReserve(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn constructors() -> Result<(), CapacityError> {
+        assert_eq!(from_first_tail(1, &[2, 3])?, non_empty_vec![1, 2, 3]);
+        assert_eq!(from_init_last(&[1, 2], 3)?, non_empty_vec![1, 2, 3]);
+        assert_eq!(from_init_last(&[], 3)?, non_empty_vec![3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn repeat_overflow() -> Result<(), CapacityError> {
+        let vec = non_empty_vec![1, 2];
+
+        assert_eq!(
+            repeat(&vec, NonZeroUsize::new(2).unwrap())?,
+            non_empty_vec![1, 2, 1, 2]
+        );
+        assert!(matches!(
+            repeat(&vec, NonZeroUsize::MAX),
+            Err(CapacityError::Overflow)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn indexing() {
+        let mut vec = non_empty_vec![10, 20, 30];
+
+        assert_eq!(get(&vec, 2), Ok(&30));
+        assert_eq!(
+            get(&vec, 3),
+            Err(OutOfBounds {
+                index: 3,
+                len: NonZeroUsize::new(3).unwrap()
+            })
+        );
+
+        *get_mut(&mut vec, 0).unwrap() = 11;
+        assert_eq!(vec, non_empty_vec![11, 20, 30]);
+        assert!(get_mut(&mut vec, 5).is_err());
+    }
+
+    #[test]
+    fn splitting() {
+        let mut vec = non_empty_vec![1, 2, 3];
+
+        let (left, right) = split_at(&vec, NonZeroUsize::new(3).unwrap()).unwrap();
+        assert_eq!(left, &non_empty_vec![1, 2, 3]);
+        assert!(right.is_empty());
+        assert!(split_at(&vec, NonZeroUsize::new(4).unwrap()).is_err());
+
+        let (left, right) = split_at_mut(&mut vec, NonZeroUsize::MIN).unwrap();
+        left.first_mut().clone_from(&right[1]);
+        assert_eq!(vec, non_empty_vec![3, 2, 3]);
+        assert!(split_at_mut(&mut vec, NonZeroUsize::MAX).is_err());
+    }
+
+    #[test]
+    fn selection() {
+        let mut vec = non_empty_vec![5, 1, 4, 2, 3];
+
+        assert_eq!(*select_nth_unstable(&mut vec, 2).unwrap().1, 3);
+        assert_eq!(
+            *select_nth_unstable_by(&mut vec, 0, |a, b| b.cmp(a))
+                .unwrap()
+                .1,
+            5
+        );
+        assert_eq!(
+            *select_nth_unstable_by_key(&mut vec, 4, |&x| x).unwrap().1,
+            5
+        );
+        assert!(select_nth_unstable(&mut vec, 5).is_err());
+        assert!(select_nth_unstable_by_key(&mut vec, 5, |&x| x).is_err());
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut vec = non_empty_vec![1, 3];
+
+        insert(&mut vec, 1, 2).unwrap();
+        insert(&mut vec, 3, 4).unwrap();
+        assert_eq!(vec, non_empty_vec![1, 2, 3, 4]);
+        assert!(matches!(
+            insert(&mut vec, 5, 0),
+            Err(InsertError::OutOfBounds(_))
+        ));
+
+        assert_eq!(remove(&mut vec, 1), Ok(2));
+        assert_eq!(swap_remove(&mut vec, 0), Ok(1));
+        assert_eq!(vec, non_empty_vec![4, 3]);
+        assert!(matches!(
+            remove(&mut vec, 2),
+            Err(RemoveError::OutOfBounds(_))
+        ));
+
+        assert_eq!(remove(&mut vec, 0), Ok(4));
+        assert_eq!(remove(&mut vec, 0), Err(RemoveError::LastElement));
+        assert_eq!(swap_remove(&mut vec, 0), Err(RemoveError::LastElement));
+        assert_eq!(vec, non_empty_vec![3]);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{NonEmptySlice, NonEmptyVec};
+
+macro_rules! impl_slice_eq {
+    ([$($vars:tt)*] $lhs:ty, $rhs:ty) => {
+        impl<$($vars)* T, U> PartialEq<$rhs> for $lhs
+        where
+            T: PartialEq<U>,
+        {
+            fn eq(&self, other: &$rhs) -> bool {
+                let lhs: &[T] = self;
+                let rhs: &[U] = other;
+                lhs == rhs
+            }
+        }
+    };
+}
+
+impl_slice_eq!([] NonEmptyVec<T>, NonEmptySlice<U>);
+impl_slice_eq!(['a,] NonEmptyVec<T>, &'a NonEmptySlice<U>);
+impl_slice_eq!([] NonEmptyVec<T>, Box<NonEmptySlice<U>>);
+impl_slice_eq!([] NonEmptyVec<T>, Vec<U>);
+impl_slice_eq!([] NonEmptyVec<T>, [U]);
+impl_slice_eq!(['a,] NonEmptyVec<T>, &'a [U]);
+impl_slice_eq!([const N: usize,] NonEmptyVec<T>, [U; N]);
+
+impl_slice_eq!([] NonEmptySlice<T>, NonEmptyVec<U>);
+impl_slice_eq!([] NonEmptySlice<T>, Box<NonEmptySlice<U>>);
+impl_slice_eq!([] NonEmptySlice<T>, Vec<U>);
+impl_slice_eq!([] NonEmptySlice<T>, [U]);
+impl_slice_eq!(['a,] NonEmptySlice<T>, &'a [U]);
+impl_slice_eq!([const N: usize,] NonEmptySlice<T>, [U; N]);
+
+impl_slice_eq!([] Box<NonEmptySlice<T>>, NonEmptyVec<U>);
+impl_slice_eq!([] Box<NonEmptySlice<T>>, NonEmptySlice<U>);
+impl_slice_eq!([] Box<NonEmptySlice<T>>, Vec<U>);
+impl_slice_eq!([] Box<NonEmptySlice<T>>, [U]);
+impl_slice_eq!(['a,] Box<NonEmptySlice<T>>, &'a [U]);
+impl_slice_eq!([const N: usize,] Box<NonEmptySlice<T>>, [U; N]);
+
+impl_slice_eq!([] Vec<T>, NonEmptyVec<U>);
+impl_slice_eq!([] Vec<T>, NonEmptySlice<U>);
+impl_slice_eq!([] Vec<T>, Box<NonEmptySlice<U>>);
+impl_slice_eq!([][T], NonEmptyVec<U>);
+impl_slice_eq!([][T], NonEmptySlice<U>);
+impl_slice_eq!(['a,] &'a [T], NonEmptyVec<U>);
+impl_slice_eq!([const N: usize,] [T; N], NonEmptyVec<U>);
+impl_slice_eq!([const N: usize,] [T; N], NonEmptySlice<U>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::non_empty_vec;
+
+    #[test]
+    fn cross_type_eq() {
+        let vec = non_empty_vec![1, 2, 3];
+        let slice: &NonEmptySlice<i32> = &vec;
+        let boxed = vec.clone().into_boxed_slice();
+
+        assert_eq!(vec, vec![1, 2, 3]);
+        assert_eq!(vec, [1, 2, 3]);
+        assert_eq!(vec, &[1, 2, 3][..]);
+        assert_eq!(vec, slice);
+        assert_eq!(vec, boxed);
+        assert_eq!(*slice, vec![1, 2, 3]);
+        assert_eq!(*slice, [1, 2, 3]);
+        assert_eq!(boxed, vec![1, 2, 3]);
+        assert_eq!(boxed, vec);
+
+        assert_eq!(vec![1, 2, 3], vec);
+        assert_eq!([1, 2, 3], vec);
+        assert_eq!(&[1, 2, 3][..], vec);
+        assert_eq!(vec![1, 2, 3], *slice);
+
+        assert_ne!(vec, vec![1, 2]);
+        assert_ne!(*slice, [1, 2, 4]);
+    }
+
+    #[test]
+    fn ord_and_hash() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let mut vecs = vec![non_empty_vec![2], non_empty_vec![1, 5], non_empty_vec![1]];
+        vecs.sort();
+
+        assert_eq!(
+            vecs,
+            vec![non_empty_vec![1], non_empty_vec![1, 5], non_empty_vec![2]]
+        );
+        assert!(non_empty_vec![1, 2].into_boxed_slice() < non_empty_vec![1, 3].into_boxed_slice());
+
+        let key = non_empty_vec![1, 2];
+        let query: &NonEmptySlice<i32> = &key;
+
+        let hashed = HashMap::from([(key.clone(), "hashed")]);
+        assert_eq!(hashed.get(query), Some(&"hashed"));
+
+        let ordered = BTreeMap::from([(key.clone(), "ordered")]);
+        assert_eq!(ordered.get(query), Some(&"ordered"));
+    }
+}
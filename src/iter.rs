@@ -0,0 +1,1680 @@
+use std::{
+    cmp::Ordering,
+    iter::{
+        Chain, Cloned, Copied, Enumerate, FilterMap, FlatMap, FusedIterator, Inspect, Map,
+        MapWhile, Peekable, Rev, Skip, SkipWhile, StepBy, Take, TakeWhile, Zip,
+    },
+    num::NonZeroUsize,
+};
+
+use crate::{vec::Empty, NonEmptySlice, NonEmptyVec};
+
+pub(crate) const EXHAUSTED: &str = "non-empty iterator yielded no items";
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl<T> Sealed for crate::NonEmptyVec<T> {}
+    impl<T> Sealed for &crate::NonEmptyVec<T> {}
+    impl<T> Sealed for &mut crate::NonEmptyVec<T> {}
+    impl<T> Sealed for &crate::NonEmptySlice<T> {}
+    impl<T> Sealed for &mut crate::NonEmptySlice<T> {}
+    impl<T> Sealed for crate::NonEmptyIter<'_, T> {}
+    impl<T> Sealed for crate::NonEmptyIterMut<'_, T> {}
+    impl<T> Sealed for crate::NonEmptyIntoIter<T> {}
+    impl<T> Sealed for crate::NonEmptyWindows<'_, T> {}
+    impl<T> Sealed for crate::NonEmptyChunks<'_, T> {}
+    impl<T, P> Sealed for crate::NonEmptyChunkBy<'_, T, P> {}
+    impl<T, E, I> Sealed for super::ResultShunt<'_, T, E, I> {}
+    impl<I, F> Sealed for super::NonEmptyMap<I, F> {}
+    impl<I> Sealed for super::NonEmptyEnumerate<I> {}
+    impl<I, U: IntoIterator, F> Sealed for super::NonEmptyFlatMap<I, U, F> {}
+    impl<A, B> Sealed for super::NonEmptyZip<A, B> {}
+    impl<I> Sealed for super::NonEmptyRev<I> {}
+    impl<I> Sealed for super::NonEmptyTake<I> {}
+    impl<I> Sealed for super::NonEmptyStepBy<I> {}
+    impl<I: Iterator> Sealed for super::NonEmptyPeekable<I> {}
+    impl<A: Iterator, B> Sealed for super::NonEmptyCartesianProduct<A, B> {}
+    impl<I: Iterator, F> Sealed for super::NonEmptyScan1<I, F> {}
+    impl<A, B> Sealed for super::NonEmptyChain<A, B> {}
+    impl<I> Sealed for super::NonEmptyCloned<I> {}
+    impl<I> Sealed for super::NonEmptyCopied<I> {}
+    impl<I, F> Sealed for super::NonEmptyInspect<I, F> {}
+}
+
+// Non-empty iterators are deliberately not `Iterator`s: stepping one requires
+// `into_iter`, which gives up the non-empty guarantee along with `self`.
+pub trait NonEmptyIterator: IntoIterator + Sized + sealed::Sealed {
+    fn next_first(self) -> (Self::Item, Self::IntoIter) {
+        let mut iter = self.into_iter();
+        let first = iter.next().expect(EXHAUSTED);
+        (first, iter)
+    }
+
+    fn first(self) -> Self::Item {
+        let (first, _) = self.next_first();
+        first
+    }
+
+    fn last(self) -> Self::Item {
+        let (first, rest) = self.next_first();
+        rest.last().unwrap_or(first)
+    }
+
+    fn map<B, F>(self, f: F) -> NonEmptyMap<Self::IntoIter, F>
+    where
+        F: FnMut(Self::Item) -> B,
+    {
+        NonEmptyMap(self.into_iter().map(f))
+    }
+
+    fn enumerate(self) -> NonEmptyEnumerate<Self::IntoIter> {
+        NonEmptyEnumerate(self.into_iter().enumerate())
+    }
+
+    fn flat_map<U, F>(self, f: F) -> NonEmptyFlatMap<Self::IntoIter, U, F>
+    where
+        U: IntoNonEmptyIterator,
+        F: FnMut(Self::Item) -> U,
+    {
+        NonEmptyFlatMap(self.into_iter().flat_map(f))
+    }
+
+    fn zip_non_empty<U>(self, other: U) -> NonEmptyZip<Self::IntoIter, U::IntoIter>
+    where
+        U: NonEmptyIterator,
+    {
+        NonEmptyZip(self.into_iter().zip(other))
+    }
+
+    fn rev(self) -> NonEmptyRev<Self::IntoIter>
+    where
+        Self::IntoIter: DoubleEndedIterator,
+    {
+        NonEmptyRev(self.into_iter().rev())
+    }
+
+    fn take(self, n: NonZeroUsize) -> NonEmptyTake<Self::IntoIter> {
+        NonEmptyTake(self.into_iter().take(n.get()))
+    }
+
+    fn step_by(self, step: NonZeroUsize) -> NonEmptyStepBy<Self::IntoIter> {
+        NonEmptyStepBy(self.into_iter().step_by(step.get()))
+    }
+
+    fn peekable(self) -> NonEmptyPeekable<Self::IntoIter> {
+        NonEmptyPeekable(self.into_iter().peekable())
+    }
+
+    fn cartesian_product<U>(self, other: U) -> NonEmptyCartesianProduct<Self::IntoIter, U::IntoIter>
+    where
+        U: IntoNonEmptyIterator,
+        U::IntoIter: Clone,
+        Self::Item: Clone,
+    {
+        NonEmptyCartesianProduct(CartesianProduct::new(self.into_iter(), other.into_iter()))
+    }
+
+    fn scan1<F>(self, f: F) -> NonEmptyScan1<Self::IntoIter, F>
+    where
+        Self::Item: Clone,
+        F: FnMut(&Self::Item, Self::Item) -> Self::Item,
+    {
+        NonEmptyScan1(Scan1 {
+            iter: self.into_iter(),
+            acc: None,
+            f,
+        })
+    }
+
+    fn chain<U>(self, other: U) -> NonEmptyChain<Self::IntoIter, U::IntoIter>
+    where
+        U: IntoIterator<Item = Self::Item>,
+    {
+        NonEmptyChain(self.into_iter().chain(other))
+    }
+
+    fn cloned<'a, T>(self) -> NonEmptyCloned<Self::IntoIter>
+    where
+        T: Clone + 'a,
+        Self: IntoIterator<Item = &'a T>,
+    {
+        NonEmptyCloned(self.into_iter().cloned())
+    }
+
+    fn copied<'a, T>(self) -> NonEmptyCopied<Self::IntoIter>
+    where
+        T: Copy + 'a,
+        Self: IntoIterator<Item = &'a T>,
+    {
+        NonEmptyCopied(self.into_iter().copied())
+    }
+
+    fn inspect<F>(self, f: F) -> NonEmptyInspect<Self::IntoIter, F>
+    where
+        F: FnMut(&Self::Item),
+    {
+        NonEmptyInspect(self.into_iter().inspect(f))
+    }
+
+    fn filter<P>(self, predicate: P) -> std::iter::Filter<Self::IntoIter, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.into_iter().filter(predicate)
+    }
+
+    fn filter_map<B, F>(self, f: F) -> FilterMap<Self::IntoIter, F>
+    where
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        self.into_iter().filter_map(f)
+    }
+
+    fn skip(self, n: usize) -> Skip<Self::IntoIter> {
+        self.into_iter().skip(n)
+    }
+
+    fn skip_while<P>(self, predicate: P) -> SkipWhile<Self::IntoIter, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.into_iter().skip_while(predicate)
+    }
+
+    fn take_while<P>(self, predicate: P) -> TakeWhile<Self::IntoIter, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.into_iter().take_while(predicate)
+    }
+
+    fn map_while<B, P>(self, predicate: P) -> MapWhile<Self::IntoIter, P>
+    where
+        P: FnMut(Self::Item) -> Option<B>,
+    {
+        self.into_iter().map_while(predicate)
+    }
+
+    fn count(self) -> usize {
+        self.into_iter().count()
+    }
+
+    fn sum<S>(self) -> S
+    where
+        S: std::iter::Sum<Self::Item>,
+    {
+        self.into_iter().sum()
+    }
+
+    fn product<P>(self) -> P
+    where
+        P: std::iter::Product<Self::Item>,
+    {
+        self.into_iter().product()
+    }
+
+    fn any<F>(self, f: F) -> bool
+    where
+        F: FnMut(Self::Item) -> bool,
+    {
+        self.into_iter().any(f)
+    }
+
+    fn all<F>(self, f: F) -> bool
+    where
+        F: FnMut(Self::Item) -> bool,
+    {
+        self.into_iter().all(f)
+    }
+
+    fn find<P>(self, predicate: P) -> Option<Self::Item>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.into_iter().find(predicate)
+    }
+
+    fn position<P>(self, predicate: P) -> Option<usize>
+    where
+        P: FnMut(Self::Item) -> bool,
+    {
+        self.into_iter().position(predicate)
+    }
+
+    fn for_each<F>(self, f: F)
+    where
+        F: FnMut(Self::Item),
+    {
+        self.into_iter().for_each(f)
+    }
+
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.into_iter().fold(init, f)
+    }
+
+    fn reduce<F>(self, f: F) -> Self::Item
+    where
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        let (first, rest) = self.next_first();
+        rest.fold(first, f)
+    }
+
+    fn fold_first<B, S, F>(self, seed: S, f: F) -> B
+    where
+        S: FnOnce(Self::Item) -> B,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let (first, rest) = self.next_first();
+        rest.fold(seed(first), f)
+    }
+
+    fn max_by<F>(self, mut compare: F) -> Self::Item
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        self.reduce(|max, value| match compare(&max, &value) {
+            Ordering::Greater => max,
+            _ => value,
+        })
+    }
+
+    fn min_by<F>(self, mut compare: F) -> Self::Item
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        self.reduce(|min, value| match compare(&min, &value) {
+            Ordering::Greater => value,
+            _ => min,
+        })
+    }
+
+    fn max_by_key<K: Ord, F>(self, mut f: F) -> Self::Item
+    where
+        F: FnMut(&Self::Item) -> K,
+    {
+        let (_, max) = self
+            .map(|value| (f(&value), value))
+            .max_by(|(a, _), (b, _)| a.cmp(b));
+        max
+    }
+
+    fn min_by_key<K: Ord, F>(self, mut f: F) -> Self::Item
+    where
+        F: FnMut(&Self::Item) -> K,
+    {
+        let (_, min) = self
+            .map(|value| (f(&value), value))
+            .min_by(|(a, _), (b, _)| a.cmp(b));
+        min
+    }
+
+    fn max(self) -> Self::Item
+    where
+        Self::Item: Ord,
+    {
+        self.max_by(Ord::cmp)
+    }
+
+    fn min(self) -> Self::Item
+    where
+        Self::Item: Ord,
+    {
+        self.min_by(Ord::cmp)
+    }
+
+    fn unzip<A, B>(self) -> (NonEmptyVec<A>, NonEmptyVec<B>)
+    where
+        Self: IntoIterator<Item = (A, B)>,
+    {
+        let ((first_a, first_b), rest) = self.next_first();
+        let (lower, _) = rest.size_hint();
+        let mut left = NonEmptyVec::with_capacity(first_a, lower.saturating_add(1));
+        let mut right = NonEmptyVec::with_capacity(first_b, lower.saturating_add(1));
+        for (a, b) in rest {
+            left.push(a);
+            right.push(b);
+        }
+        (left, right)
+    }
+
+    fn collect<B>(self) -> B
+    where
+        B: FromIterator<Self::Item>,
+    {
+        self.into_iter().collect()
+    }
+
+    fn collect_non_empty<C>(self) -> C
+    where
+        C: FromNonEmptyIterator<Self::Item>,
+    {
+        C::from_non_empty_iter(self)
+    }
+}
+
+pub trait IntoNonEmptyIterator: IntoIterator + sealed::Sealed {}
+
+impl<I: NonEmptyIterator> IntoNonEmptyIterator for I {}
+
+impl<T> IntoNonEmptyIterator for NonEmptyVec<T> {}
+
+impl<T> IntoNonEmptyIterator for &NonEmptyVec<T> {}
+
+impl<T> IntoNonEmptyIterator for &mut NonEmptyVec<T> {}
+
+impl<T> IntoNonEmptyIterator for &NonEmptySlice<T> {}
+
+impl<T> IntoNonEmptyIterator for &mut NonEmptySlice<T> {}
+
+pub trait FromNonEmptyIterator<T>: Sized {
+    fn from_non_empty_iter<I>(iter: I) -> Self
+    where
+        I: NonEmptyIterator<Item = T>;
+}
+
+impl<T> FromNonEmptyIterator<T> for NonEmptyVec<T> {
+    fn from_non_empty_iter<I>(iter: I) -> Self
+    where
+        I: NonEmptyIterator<Item = T>,
+    {
+        let (first, rest) = iter.next_first();
+        let (lower, _) = rest.size_hint();
+        let mut vec = NonEmptyVec::with_capacity(first, lower.saturating_add(1));
+        vec.extend(rest);
+        vec
+    }
+}
+
+impl<T> FromNonEmptyIterator<T> for Box<NonEmptySlice<T>> {
+    fn from_non_empty_iter<I>(iter: I) -> Self
+    where
+        I: NonEmptyIterator<Item = T>,
+    {
+        NonEmptyVec::from_non_empty_iter(iter).into_boxed_slice()
+    }
+}
+
+impl<T> FromNonEmptyIterator<T> for Vec<T> {
+    fn from_non_empty_iter<I>(iter: I) -> Self
+    where
+        I: NonEmptyIterator<Item = T>,
+    {
+        iter.into_iter().collect()
+    }
+}
+
+impl<T, E, C> FromNonEmptyIterator<Result<T, E>> for Result<C, E>
+where
+    C: FromNonEmptyIterator<T>,
+{
+    fn from_non_empty_iter<I>(iter: I) -> Self
+    where
+        I: NonEmptyIterator<Item = Result<T, E>>,
+    {
+        let (first, rest) = iter.next_first();
+        let mut error = None;
+        let collected = C::from_non_empty_iter(ResultShunt {
+            first: Some(first?),
+            rest,
+            error: &mut error,
+        });
+        match error {
+            Some(error) => Err(error),
+            None => Ok(collected),
+        }
+    }
+}
+
+pub trait IteratorExt: Iterator + Sized {
+    fn try_collect_non_empty(self) -> Result<NonEmptyVec<Self::Item>, Empty> {
+        NonEmptyVec::try_from_iter(self)
+    }
+
+    fn collect_non_empty_or(self, default: Self::Item) -> NonEmptyVec<Self::Item> {
+        self.try_collect_non_empty()
+            .unwrap_or_else(|_| NonEmptyVec::one(default))
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+struct ResultShunt<'a, T, E, I> {
+    first: Option<T>,
+    rest: I,
+    error: &'a mut Option<E>,
+}
+
+impl<T, E, I> Iterator for ResultShunt<'_, T, E, I>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if let Some(first) = self.first.take() {
+            return Some(first);
+        }
+        if self.error.is_some() {
+            return None;
+        }
+        match self.rest.next()? {
+            Ok(value) => Some(value),
+            Err(error) => {
+                *self.error = Some(error);
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let first = usize::from(self.first.is_some());
+        let (_, upper) = self.rest.size_hint();
+        (first, upper.and_then(|upper| upper.checked_add(first)))
+    }
+}
+
+impl<T, E, I> NonEmptyIterator for ResultShunt<'_, T, E, I> where I: Iterator<Item = Result<T, E>> {}
+
+// Forwarding inherent methods so concrete non-empty iterators can be used
+// without `NonEmptyIterator` in scope. Expects a newtype over `IntoIter`.
+macro_rules! non_empty_iterator_methods {
+    () => {
+        pub fn size_hint(&self) -> (usize, Option<usize>) {
+            Iterator::size_hint(&self.0)
+        }
+
+        pub fn non_zero_len(&self) -> std::num::NonZeroUsize
+        where
+            <Self as IntoIterator>::IntoIter: ExactSizeIterator,
+        {
+            std::num::NonZeroUsize::new(ExactSizeIterator::len(&self.0))
+                .expect($crate::iter::EXHAUSTED)
+        }
+
+        pub fn next_first(
+            self,
+        ) -> (
+            <Self as IntoIterator>::Item,
+            <Self as IntoIterator>::IntoIter,
+        ) {
+            $crate::NonEmptyIterator::next_first(self)
+        }
+
+        pub fn first(self) -> <Self as IntoIterator>::Item {
+            $crate::NonEmptyIterator::first(self)
+        }
+
+        pub fn last(self) -> <Self as IntoIterator>::Item {
+            $crate::NonEmptyIterator::last(self)
+        }
+
+        pub fn map<R, G>(self, f: G) -> $crate::NonEmptyMap<<Self as IntoIterator>::IntoIter, G>
+        where
+            G: FnMut(<Self as IntoIterator>::Item) -> R,
+        {
+            $crate::NonEmptyIterator::map(self, f)
+        }
+
+        pub fn enumerate(self) -> $crate::NonEmptyEnumerate<<Self as IntoIterator>::IntoIter> {
+            $crate::NonEmptyIterator::enumerate(self)
+        }
+
+        pub fn flat_map<O, G>(
+            self,
+            f: G,
+        ) -> $crate::NonEmptyFlatMap<<Self as IntoIterator>::IntoIter, O, G>
+        where
+            O: $crate::IntoNonEmptyIterator,
+            G: FnMut(<Self as IntoIterator>::Item) -> O,
+        {
+            $crate::NonEmptyIterator::flat_map(self, f)
+        }
+
+        pub fn zip_non_empty<O>(
+            self,
+            other: O,
+        ) -> $crate::NonEmptyZip<<Self as IntoIterator>::IntoIter, O::IntoIter>
+        where
+            O: $crate::NonEmptyIterator,
+        {
+            $crate::NonEmptyIterator::zip_non_empty(self, other)
+        }
+
+        pub fn rev(self) -> $crate::NonEmptyRev<<Self as IntoIterator>::IntoIter>
+        where
+            <Self as IntoIterator>::IntoIter: DoubleEndedIterator,
+        {
+            $crate::NonEmptyIterator::rev(self)
+        }
+
+        pub fn take(
+            self,
+            n: std::num::NonZeroUsize,
+        ) -> $crate::NonEmptyTake<<Self as IntoIterator>::IntoIter> {
+            $crate::NonEmptyIterator::take(self, n)
+        }
+
+        pub fn step_by(
+            self,
+            step: std::num::NonZeroUsize,
+        ) -> $crate::NonEmptyStepBy<<Self as IntoIterator>::IntoIter> {
+            $crate::NonEmptyIterator::step_by(self, step)
+        }
+
+        pub fn peekable(self) -> $crate::NonEmptyPeekable<<Self as IntoIterator>::IntoIter> {
+            $crate::NonEmptyIterator::peekable(self)
+        }
+
+        pub fn cartesian_product<O>(
+            self,
+            other: O,
+        ) -> $crate::NonEmptyCartesianProduct<<Self as IntoIterator>::IntoIter, O::IntoIter>
+        where
+            O: $crate::IntoNonEmptyIterator,
+            O::IntoIter: Clone,
+            <Self as IntoIterator>::Item: Clone,
+        {
+            $crate::NonEmptyIterator::cartesian_product(self, other)
+        }
+
+        pub fn scan1<G>(self, f: G) -> $crate::NonEmptyScan1<<Self as IntoIterator>::IntoIter, G>
+        where
+            <Self as IntoIterator>::Item: Clone,
+            G: FnMut(
+                &<Self as IntoIterator>::Item,
+                <Self as IntoIterator>::Item,
+            ) -> <Self as IntoIterator>::Item,
+        {
+            $crate::NonEmptyIterator::scan1(self, f)
+        }
+
+        pub fn chain<O>(
+            self,
+            other: O,
+        ) -> $crate::NonEmptyChain<<Self as IntoIterator>::IntoIter, O::IntoIter>
+        where
+            O: IntoIterator<Item = <Self as IntoIterator>::Item>,
+        {
+            $crate::NonEmptyIterator::chain(self, other)
+        }
+
+        pub fn cloned<'b, X>(self) -> $crate::NonEmptyCloned<<Self as IntoIterator>::IntoIter>
+        where
+            X: Clone + 'b,
+            Self: IntoIterator<Item = &'b X>,
+        {
+            $crate::NonEmptyIterator::cloned(self)
+        }
+
+        pub fn copied<'b, X>(self) -> $crate::NonEmptyCopied<<Self as IntoIterator>::IntoIter>
+        where
+            X: Copy + 'b,
+            Self: IntoIterator<Item = &'b X>,
+        {
+            $crate::NonEmptyIterator::copied(self)
+        }
+
+        pub fn inspect<G>(
+            self,
+            f: G,
+        ) -> $crate::NonEmptyInspect<<Self as IntoIterator>::IntoIter, G>
+        where
+            G: FnMut(&<Self as IntoIterator>::Item),
+        {
+            $crate::NonEmptyIterator::inspect(self, f)
+        }
+
+        pub fn filter<Q>(
+            self,
+            predicate: Q,
+        ) -> std::iter::Filter<<Self as IntoIterator>::IntoIter, Q>
+        where
+            Q: FnMut(&<Self as IntoIterator>::Item) -> bool,
+        {
+            $crate::NonEmptyIterator::filter(self, predicate)
+        }
+
+        pub fn filter_map<R, G>(
+            self,
+            f: G,
+        ) -> std::iter::FilterMap<<Self as IntoIterator>::IntoIter, G>
+        where
+            G: FnMut(<Self as IntoIterator>::Item) -> Option<R>,
+        {
+            $crate::NonEmptyIterator::filter_map(self, f)
+        }
+
+        pub fn skip(self, n: usize) -> std::iter::Skip<<Self as IntoIterator>::IntoIter> {
+            $crate::NonEmptyIterator::skip(self, n)
+        }
+
+        pub fn skip_while<Q>(
+            self,
+            predicate: Q,
+        ) -> std::iter::SkipWhile<<Self as IntoIterator>::IntoIter, Q>
+        where
+            Q: FnMut(&<Self as IntoIterator>::Item) -> bool,
+        {
+            $crate::NonEmptyIterator::skip_while(self, predicate)
+        }
+
+        pub fn take_while<Q>(
+            self,
+            predicate: Q,
+        ) -> std::iter::TakeWhile<<Self as IntoIterator>::IntoIter, Q>
+        where
+            Q: FnMut(&<Self as IntoIterator>::Item) -> bool,
+        {
+            $crate::NonEmptyIterator::take_while(self, predicate)
+        }
+
+        pub fn map_while<R, Q>(
+            self,
+            predicate: Q,
+        ) -> std::iter::MapWhile<<Self as IntoIterator>::IntoIter, Q>
+        where
+            Q: FnMut(<Self as IntoIterator>::Item) -> Option<R>,
+        {
+            $crate::NonEmptyIterator::map_while(self, predicate)
+        }
+
+        pub fn count(self) -> usize {
+            $crate::NonEmptyIterator::count(self)
+        }
+
+        pub fn sum<S>(self) -> S
+        where
+            S: std::iter::Sum<<Self as IntoIterator>::Item>,
+        {
+            $crate::NonEmptyIterator::sum(self)
+        }
+
+        pub fn product<Q>(self) -> Q
+        where
+            Q: std::iter::Product<<Self as IntoIterator>::Item>,
+        {
+            $crate::NonEmptyIterator::product(self)
+        }
+
+        pub fn any<G>(self, f: G) -> bool
+        where
+            G: FnMut(<Self as IntoIterator>::Item) -> bool,
+        {
+            $crate::NonEmptyIterator::any(self, f)
+        }
+
+        pub fn all<G>(self, f: G) -> bool
+        where
+            G: FnMut(<Self as IntoIterator>::Item) -> bool,
+        {
+            $crate::NonEmptyIterator::all(self, f)
+        }
+
+        pub fn find<Q>(self, predicate: Q) -> Option<<Self as IntoIterator>::Item>
+        where
+            Q: FnMut(&<Self as IntoIterator>::Item) -> bool,
+        {
+            $crate::NonEmptyIterator::find(self, predicate)
+        }
+
+        pub fn position<Q>(self, predicate: Q) -> Option<usize>
+        where
+            Q: FnMut(<Self as IntoIterator>::Item) -> bool,
+        {
+            $crate::NonEmptyIterator::position(self, predicate)
+        }
+
+        pub fn for_each<G>(self, f: G)
+        where
+            G: FnMut(<Self as IntoIterator>::Item),
+        {
+            $crate::NonEmptyIterator::for_each(self, f)
+        }
+
+        pub fn fold<R, G>(self, init: R, f: G) -> R
+        where
+            G: FnMut(R, <Self as IntoIterator>::Item) -> R,
+        {
+            $crate::NonEmptyIterator::fold(self, init, f)
+        }
+
+        pub fn reduce<G>(self, f: G) -> <Self as IntoIterator>::Item
+        where
+            G: FnMut(
+                <Self as IntoIterator>::Item,
+                <Self as IntoIterator>::Item,
+            ) -> <Self as IntoIterator>::Item,
+        {
+            $crate::NonEmptyIterator::reduce(self, f)
+        }
+
+        pub fn fold_first<R, S, G>(self, seed: S, f: G) -> R
+        where
+            S: FnOnce(<Self as IntoIterator>::Item) -> R,
+            G: FnMut(R, <Self as IntoIterator>::Item) -> R,
+        {
+            $crate::NonEmptyIterator::fold_first(self, seed, f)
+        }
+
+        pub fn max_by<G>(self, compare: G) -> <Self as IntoIterator>::Item
+        where
+            G: FnMut(
+                &<Self as IntoIterator>::Item,
+                &<Self as IntoIterator>::Item,
+            ) -> std::cmp::Ordering,
+        {
+            $crate::NonEmptyIterator::max_by(self, compare)
+        }
+
+        pub fn min_by<G>(self, compare: G) -> <Self as IntoIterator>::Item
+        where
+            G: FnMut(
+                &<Self as IntoIterator>::Item,
+                &<Self as IntoIterator>::Item,
+            ) -> std::cmp::Ordering,
+        {
+            $crate::NonEmptyIterator::min_by(self, compare)
+        }
+
+        pub fn max_by_key<K: Ord, G>(self, f: G) -> <Self as IntoIterator>::Item
+        where
+            G: FnMut(&<Self as IntoIterator>::Item) -> K,
+        {
+            $crate::NonEmptyIterator::max_by_key(self, f)
+        }
+
+        pub fn min_by_key<K: Ord, G>(self, f: G) -> <Self as IntoIterator>::Item
+        where
+            G: FnMut(&<Self as IntoIterator>::Item) -> K,
+        {
+            $crate::NonEmptyIterator::min_by_key(self, f)
+        }
+
+        pub fn max(self) -> <Self as IntoIterator>::Item
+        where
+            <Self as IntoIterator>::Item: Ord,
+        {
+            $crate::NonEmptyIterator::max(self)
+        }
+
+        pub fn min(self) -> <Self as IntoIterator>::Item
+        where
+            <Self as IntoIterator>::Item: Ord,
+        {
+            $crate::NonEmptyIterator::min(self)
+        }
+
+        pub fn unzip<R, S>(self) -> ($crate::NonEmptyVec<R>, $crate::NonEmptyVec<S>)
+        where
+            Self: IntoIterator<Item = (R, S)>,
+        {
+            $crate::NonEmptyIterator::unzip(self)
+        }
+
+        pub fn collect<R>(self) -> R
+        where
+            R: FromIterator<<Self as IntoIterator>::Item>,
+        {
+            $crate::NonEmptyIterator::collect(self)
+        }
+
+        pub fn collect_non_empty<C>(self) -> C
+        where
+            C: $crate::FromNonEmptyIterator<<Self as IntoIterator>::Item>,
+        {
+            $crate::NonEmptyIterator::collect_non_empty(self)
+        }
+    };
+}
+
+pub(crate) use non_empty_iterator_methods;
+
+pub struct NonEmptyMap<I, F>(Map<I, F>);
+
+impl<B, I: Iterator, F> NonEmptyMap<I, F>
+where
+    F: FnMut(I::Item) -> B,
+{
+    non_empty_iterator_methods!();
+}
+
+impl<B, I: Iterator, F> IntoIterator for NonEmptyMap<I, F>
+where
+    F: FnMut(I::Item) -> B,
+{
+    type Item = B;
+    type IntoIter = Map<I, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<B, I: Iterator, F> NonEmptyIterator for NonEmptyMap<I, F> where F: FnMut(I::Item) -> B {}
+
+pub struct NonEmptyEnumerate<I>(Enumerate<I>);
+
+impl<I: Iterator> NonEmptyEnumerate<I> {
+    non_empty_iterator_methods!();
+}
+
+impl<I: Iterator> IntoIterator for NonEmptyEnumerate<I> {
+    type Item = (usize, I::Item);
+    type IntoIter = Enumerate<I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<I: Iterator> NonEmptyIterator for NonEmptyEnumerate<I> {}
+
+pub struct NonEmptyFlatMap<I, U: IntoIterator, F>(FlatMap<I, U, F>);
+
+impl<I: Iterator, U: IntoIterator, F> NonEmptyFlatMap<I, U, F>
+where
+    F: FnMut(I::Item) -> U,
+{
+    non_empty_iterator_methods!();
+}
+
+impl<I: Iterator, U: IntoIterator, F> IntoIterator for NonEmptyFlatMap<I, U, F>
+where
+    F: FnMut(I::Item) -> U,
+{
+    type Item = U::Item;
+    type IntoIter = FlatMap<I, U, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<I: Iterator, U: IntoIterator, F> NonEmptyIterator for NonEmptyFlatMap<I, U, F> where
+    F: FnMut(I::Item) -> U
+{
+}
+
+pub struct NonEmptyZip<A, B>(Zip<A, B>);
+
+impl<A: Iterator, B: Iterator> NonEmptyZip<A, B> {
+    non_empty_iterator_methods!();
+}
+
+impl<A: Iterator, B: Iterator> IntoIterator for NonEmptyZip<A, B> {
+    type Item = (A::Item, B::Item);
+    type IntoIter = Zip<A, B>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<A: Iterator, B: Iterator> NonEmptyIterator for NonEmptyZip<A, B> {}
+
+pub struct NonEmptyRev<I>(Rev<I>);
+
+impl<I: DoubleEndedIterator> NonEmptyRev<I> {
+    non_empty_iterator_methods!();
+}
+
+impl<I: DoubleEndedIterator> IntoIterator for NonEmptyRev<I> {
+    type Item = I::Item;
+    type IntoIter = Rev<I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<I: DoubleEndedIterator> NonEmptyIterator for NonEmptyRev<I> {}
+
+pub struct NonEmptyTake<I>(Take<I>);
+
+impl<I: Iterator> NonEmptyTake<I> {
+    non_empty_iterator_methods!();
+}
+
+impl<I: Iterator> IntoIterator for NonEmptyTake<I> {
+    type Item = I::Item;
+    type IntoIter = Take<I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<I: Iterator> NonEmptyIterator for NonEmptyTake<I> {}
+
+pub struct NonEmptyStepBy<I>(StepBy<I>);
+
+impl<I: Iterator> NonEmptyStepBy<I> {
+    non_empty_iterator_methods!();
+}
+
+impl<I: Iterator> IntoIterator for NonEmptyStepBy<I> {
+    type Item = I::Item;
+    type IntoIter = StepBy<I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<I: Iterator> NonEmptyIterator for NonEmptyStepBy<I> {}
+
+pub struct NonEmptyPeekable<I: Iterator>(Peekable<I>);
+
+impl<I: Iterator> NonEmptyPeekable<I> {
+    pub fn peek_first(&mut self) -> &I::Item {
+        self.0.peek().expect(EXHAUSTED)
+    }
+
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        self.0.peek()
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut I::Item> {
+        self.0.peek_mut()
+    }
+
+    non_empty_iterator_methods!();
+}
+
+impl<I: Iterator> IntoIterator for NonEmptyPeekable<I> {
+    type Item = I::Item;
+    type IntoIter = Peekable<I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<I: Iterator> NonEmptyIterator for NonEmptyPeekable<I> {}
+
+pub struct CartesianProduct<A: Iterator, B> {
+    left: A,
+    current: Option<A::Item>,
+    right: B,
+    right_start: B,
+}
+
+impl<A: Iterator, B: Clone> CartesianProduct<A, B> {
+    fn new(left: A, right: B) -> Self {
+        CartesianProduct {
+            left,
+            current: None,
+            right: right.clone(),
+            right_start: right,
+        }
+    }
+}
+
+impl<A, B> Iterator for CartesianProduct<A, B>
+where
+    A: Iterator<Item: Clone>,
+    B: Iterator + Clone,
+{
+    type Item = (A::Item, B::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match &self.current {
+                Some(current) => match self.right.next() {
+                    Some(value) => return Some((current.clone(), value)),
+                    None => self.current = None,
+                },
+                None => {
+                    self.current = Some(self.left.next()?);
+                    self.right = self.right_start.clone();
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left_lower, left_upper) = self.left.size_hint();
+        let (start_lower, start_upper) = self.right_start.size_hint();
+        let (right_lower, right_upper) = match self.current {
+            Some(_) => self.right.size_hint(),
+            None => (0, Some(0)),
+        };
+        let lower = left_lower
+            .saturating_mul(start_lower)
+            .saturating_add(right_lower);
+        let upper = left_upper
+            .zip(start_upper)
+            .and_then(|(left, start)| left.checked_mul(start))
+            .zip(right_upper)
+            .and_then(|(rest, right)| rest.checked_add(right));
+        (lower, upper)
+    }
+}
+
+impl<A, B> FusedIterator for CartesianProduct<A, B>
+where
+    A: FusedIterator<Item: Clone>,
+    B: Iterator + Clone,
+{
+}
+
+pub struct NonEmptyCartesianProduct<A: Iterator, B>(CartesianProduct<A, B>);
+
+impl<A, B> NonEmptyCartesianProduct<A, B>
+where
+    A: Iterator<Item: Clone>,
+    B: Iterator + Clone,
+{
+    non_empty_iterator_methods!();
+}
+
+impl<A, B> IntoIterator for NonEmptyCartesianProduct<A, B>
+where
+    A: Iterator<Item: Clone>,
+    B: Iterator + Clone,
+{
+    type Item = (A::Item, B::Item);
+    type IntoIter = CartesianProduct<A, B>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<A, B> NonEmptyIterator for NonEmptyCartesianProduct<A, B>
+where
+    A: Iterator<Item: Clone>,
+    B: Iterator + Clone,
+{
+}
+
+pub struct Scan1<I: Iterator, F> {
+    iter: I,
+    acc: Option<I::Item>,
+    f: F,
+}
+
+impl<I, F> Iterator for Scan1<I, F>
+where
+    I: Iterator<Item: Clone>,
+    F: FnMut(&I::Item, I::Item) -> I::Item,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.iter.next()?;
+        let acc = match &self.acc {
+            Some(acc) => (self.f)(acc, value),
+            None => value,
+        };
+        self.acc = Some(acc.clone());
+        Some(acc)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> ExactSizeIterator for Scan1<I, F>
+where
+    I: ExactSizeIterator<Item: Clone>,
+    F: FnMut(&I::Item, I::Item) -> I::Item,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, F> FusedIterator for Scan1<I, F>
+where
+    I: FusedIterator<Item: Clone>,
+    F: FnMut(&I::Item, I::Item) -> I::Item,
+{
+}
+
+pub struct NonEmptyScan1<I: Iterator, F>(Scan1<I, F>);
+
+impl<I, F> NonEmptyScan1<I, F>
+where
+    I: Iterator<Item: Clone>,
+    F: FnMut(&I::Item, I::Item) -> I::Item,
+{
+    non_empty_iterator_methods!();
+}
+
+impl<I, F> IntoIterator for NonEmptyScan1<I, F>
+where
+    I: Iterator<Item: Clone>,
+    F: FnMut(&I::Item, I::Item) -> I::Item,
+{
+    type Item = I::Item;
+    type IntoIter = Scan1<I, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<I, F> NonEmptyIterator for NonEmptyScan1<I, F>
+where
+    I: Iterator<Item: Clone>,
+    F: FnMut(&I::Item, I::Item) -> I::Item,
+{
+}
+
+pub struct NonEmptyChain<A, B>(Chain<A, B>);
+
+impl<A, B> NonEmptyChain<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    non_empty_iterator_methods!();
+}
+
+impl<A, B> IntoIterator for NonEmptyChain<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+    type IntoIter = Chain<A, B>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<A, B> NonEmptyIterator for NonEmptyChain<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+}
+
+pub struct NonEmptyCloned<I>(Cloned<I>);
+
+impl<'a, I, T> NonEmptyCloned<I>
+where
+    I: Iterator<Item = &'a T>,
+    T: Clone + 'a,
+{
+    non_empty_iterator_methods!();
+}
+
+impl<'a, I, T> IntoIterator for NonEmptyCloned<I>
+where
+    I: Iterator<Item = &'a T>,
+    T: Clone + 'a,
+{
+    type Item = T;
+    type IntoIter = Cloned<I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<'a, I, T> NonEmptyIterator for NonEmptyCloned<I>
+where
+    I: Iterator<Item = &'a T>,
+    T: Clone + 'a,
+{
+}
+
+pub struct NonEmptyCopied<I>(Copied<I>);
+
+impl<'a, I, T> NonEmptyCopied<I>
+where
+    I: Iterator<Item = &'a T>,
+    T: Copy + 'a,
+{
+    non_empty_iterator_methods!();
+}
+
+impl<'a, I, T> IntoIterator for NonEmptyCopied<I>
+where
+    I: Iterator<Item = &'a T>,
+    T: Copy + 'a,
+{
+    type Item = T;
+    type IntoIter = Copied<I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<'a, I, T> NonEmptyIterator for NonEmptyCopied<I>
+where
+    I: Iterator<Item = &'a T>,
+    T: Copy + 'a,
+{
+}
+
+pub struct NonEmptyInspect<I, F>(Inspect<I, F>);
+
+impl<I: Iterator, F> NonEmptyInspect<I, F>
+where
+    F: FnMut(&I::Item),
+{
+    non_empty_iterator_methods!();
+}
+
+impl<I: Iterator, F> IntoIterator for NonEmptyInspect<I, F>
+where
+    F: FnMut(&I::Item),
+{
+    type Item = I::Item;
+    type IntoIter = Inspect<I, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<I: Iterator, F> NonEmptyIterator for NonEmptyInspect<I, F> where F: FnMut(&I::Item) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::non_empty_vec;
+
+    #[test]
+    fn adapters() {
+        let vec = non_empty_vec![1, 2, 3];
+        let mut seen = Vec::new();
+
+        let result = vec
+            .iter()
+            .copied()
+            .map(|v| v * 10)
+            .chain([40])
+            .inspect(|v| seen.push(*v))
+            .collect_non_empty::<NonEmptyVec<_>>();
+
+        assert_eq!(result, non_empty_vec![10, 20, 30, 40]);
+        assert_eq!(seen, vec![10, 20, 30, 40]);
+
+        let strings = non_empty_vec!["a".to_string(), "b".to_string()];
+        assert_eq!(
+            strings
+                .iter()
+                .cloned()
+                .collect_non_empty::<NonEmptyVec<_>>(),
+            strings
+        );
+    }
+
+    #[test]
+    fn lossy_adapters_are_plain_iterators() {
+        let vec = non_empty_vec![1, 2, 3, 4];
+
+        let evens: Vec<i32> = vec.iter().copied().filter(|v| v % 2 == 0).collect();
+        assert_eq!(evens, vec![2, 4]);
+
+        let rest: Vec<&i32> = vec.iter().skip(3).collect();
+        assert_eq!(rest, vec![&4]);
+    }
+
+    #[test]
+    fn generic_non_empty_iterator() {
+        fn sum_doubled<I: NonEmptyIterator<Item = i32>>(iter: I) -> NonEmptyVec<i32> {
+            iter.map(|v| v * 2).collect_non_empty()
+        }
+
+        let vec = non_empty_vec![1, 2];
+        assert_eq!(sum_doubled(vec.iter().copied()), non_empty_vec![2, 4]);
+
+        let (first, rest) = vec.iter().next_first();
+        assert_eq!(first, &1);
+        assert_eq!(rest.len(), 1);
+    }
+
+    #[test]
+    fn standard_iterator_traits() {
+        fn assert_traits<I>(_: &I)
+        where
+            I: IntoIterator<IntoIter: DoubleEndedIterator + ExactSizeIterator + FusedIterator>,
+        {
+        }
+
+        let vec = non_empty_vec![1, 2, 3];
+        let iter = vec.iter().copied().map(|v| v + 1).inspect(|_| ());
+
+        assert_traits(&vec.iter());
+        assert_traits(&iter);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(
+            iter.rev().collect_non_empty::<NonEmptyVec<_>>(),
+            non_empty_vec![4, 3, 2]
+        );
+
+        let one = non_empty_vec![1];
+        assert!(one.iter().size_hint().0 >= 1);
+        assert!(one.iter().chain(None).size_hint().0 >= 1);
+
+        let mut chain = vec.iter().chain(&[4]).into_iter();
+        assert_eq!(chain.next_back(), Some(&4));
+    }
+
+    #[test]
+    fn reduce() {
+        let vec = non_empty_vec![3, 1, 2];
+
+        assert_eq!(vec.iter().copied().reduce(|a, b| a * 10 + b), 312);
+        assert_eq!(vec.iter().reduce(|a, b| a.max(b)), &3);
+        assert_eq!(
+            vec.iter()
+                .fold_first(|v| v.to_string(), |acc, v| format!("{acc}-{v}")),
+            "3-1-2"
+        );
+        assert_eq!(
+            non_empty_vec![7].non_empty_into_iter().reduce(|a, b| a + b),
+            7
+        );
+    }
+
+    #[test]
+    fn min_max() {
+        let pairs = non_empty_vec![(1, 'a'), (2, 'b'), (2, 'c'), (1, 'd')];
+
+        assert_eq!(pairs.iter().max(), &(2, 'c'));
+        assert_eq!(pairs.iter().min(), &(1, 'a'));
+        assert_eq!(pairs.iter().max_by_key(|p| p.0), &(2, 'c'));
+        assert_eq!(pairs.iter().min_by_key(|p| p.0), &(1, 'a'));
+        assert_eq!(pairs.iter().max_by(|a, b| a.1.cmp(&b.1)), &(1, 'd'));
+        assert_eq!(pairs.iter().min_by(|a, b| a.1.cmp(&b.1)), &(1, 'a'));
+        assert_eq!(pairs.iter().copied().map(|p| p.1).max(), 'd');
+    }
+
+    #[test]
+    fn first_last() {
+        let vec = non_empty_vec![1, 2, 3];
+
+        assert_eq!(vec.iter().first(), &1);
+        assert_eq!(vec.iter().last(), &3);
+        assert_eq!(vec.iter().map(|v| v * 2).last(), 6);
+        assert_eq!(non_empty_vec![1].non_empty_into_iter().last(), 1);
+    }
+
+    #[test]
+    fn enumerate() {
+        let vec = non_empty_vec!['a', 'b'];
+
+        assert_eq!(
+            vec.iter().enumerate().collect_non_empty::<NonEmptyVec<_>>(),
+            non_empty_vec![(0, &'a'), (1, &'b')]
+        );
+        assert_eq!(
+            vec.non_empty_into_iter()
+                .enumerate()
+                .collect_non_empty::<NonEmptyVec<_>>(),
+            non_empty_vec![(0, 'a'), (1, 'b')]
+        );
+    }
+
+    #[test]
+    fn zip_non_empty() {
+        let left = non_empty_vec![1, 2, 3];
+        let right = non_empty_vec!['a', 'b'];
+
+        let pairs = left
+            .iter()
+            .copied()
+            .zip_non_empty(right.iter())
+            .collect_non_empty::<NonEmptyVec<_>>();
+        assert_eq!(pairs, non_empty_vec![(1, &'a'), (2, &'b')]);
+
+        let zipped = left.iter().zip_non_empty(right.iter());
+        assert_eq!(zipped.non_zero_len().get(), 2);
+    }
+
+    #[test]
+    fn rev() {
+        let vec = non_empty_vec![1, 2, 3];
+
+        assert_eq!(
+            vec.iter().rev().collect_non_empty::<NonEmptyVec<_>>(),
+            non_empty_vec![&3, &2, &1]
+        );
+        assert_eq!(vec.iter().rev().first(), &3);
+        assert_eq!(
+            vec.non_empty_into_iter()
+                .rev()
+                .collect_non_empty::<NonEmptyVec<_>>(),
+            non_empty_vec![3, 2, 1]
+        );
+    }
+
+    #[test]
+    fn take_step_by() {
+        let vec = non_empty_vec![1, 2, 3, 4, 5];
+        let two = NonZeroUsize::new(2).unwrap();
+
+        assert_eq!(
+            vec.iter()
+                .copied()
+                .take(two)
+                .collect_non_empty::<NonEmptyVec<_>>(),
+            non_empty_vec![1, 2]
+        );
+        assert_eq!(
+            vec.iter()
+                .copied()
+                .step_by(two)
+                .collect_non_empty::<NonEmptyVec<_>>(),
+            non_empty_vec![1, 3, 5]
+        );
+        assert_eq!(
+            non_empty_vec![1]
+                .iter()
+                .step_by(two)
+                .take(two)
+                .collect_non_empty::<NonEmptyVec<_>>(),
+            non_empty_vec![&1]
+        );
+    }
+
+    #[test]
+    fn peekable() {
+        let tokens = non_empty_vec!["let", "x"];
+        let mut iter = tokens.iter().copied().peekable();
+
+        assert_eq!(iter.peek_first(), &"let");
+        assert_eq!(iter.peek(), Some(&"let"));
+        assert_eq!(
+            iter.collect_non_empty::<NonEmptyVec<_>>(),
+            non_empty_vec!["let", "x"]
+        );
+    }
+
+    #[test]
+    fn from_non_empty_iterator() {
+        let vec = non_empty_vec![1, 2, 3];
+
+        let boxed: Box<NonEmptySlice<i32>> = vec.iter().copied().collect_non_empty();
+        assert_eq!(boxed, vec);
+
+        let plain: Vec<i32> = vec.iter().copied().collect();
+        assert_eq!(plain, vec![1, 2, 3]);
+
+        let parsed: Result<NonEmptyVec<i32>, _> = non_empty_vec!["1", "2"]
+            .iter()
+            .map(|s| s.parse::<i32>())
+            .collect_non_empty();
+        assert_eq!(parsed.unwrap(), non_empty_vec![1, 2]);
+
+        let failed: Result<NonEmptyVec<i32>, _> = non_empty_vec!["1", "x", "3"]
+            .iter()
+            .map(|s| s.parse::<i32>())
+            .collect_non_empty();
+        assert!(failed.is_err());
+
+        let first_failed: Result<NonEmptyVec<i32>, _> = non_empty_vec!["x"]
+            .iter()
+            .map(|s| s.parse::<i32>())
+            .collect_non_empty();
+        assert!(first_failed.is_err());
+    }
+
+    #[test]
+    fn collect_into_std_collections() {
+        use std::collections::{BTreeMap, HashSet};
+
+        let vec = non_empty_vec![1, 2, 2];
+
+        let set = vec.iter().copied().collect::<HashSet<_>>();
+        assert_eq!(set, HashSet::from([1, 2]));
+
+        let text = non_empty_vec!['a', 'b'].iter().copied().collect::<String>();
+        assert_eq!(text, "ab");
+
+        let map = vec.iter().copied().enumerate().collect::<BTreeMap<_, _>>();
+        assert_eq!(map, BTreeMap::from([(0, 1), (1, 2), (2, 2)]));
+    }
+
+    #[test]
+    fn iterator_ext() {
+        let evens = (1..=4).filter(|v| v % 2 == 0).try_collect_non_empty();
+        assert_eq!(evens.unwrap(), non_empty_vec![2, 4]);
+
+        assert!(std::iter::empty::<i32>().try_collect_non_empty().is_err());
+        assert_eq!((1..1).collect_non_empty_or(0), non_empty_vec![0]);
+        assert_eq!((1..3).collect_non_empty_or(0), non_empty_vec![1, 2]);
+    }
+
+    #[test]
+    fn map_is_lazy_and_composable() {
+        let vec = non_empty_vec![1, 2, 3];
+        let mut calls = 0;
+
+        let mapped = vec.iter().map(|v| {
+            calls += 1;
+            v * 10
+        });
+        let boxed: Box<NonEmptySlice<(usize, i32)>> = mapped
+            .rev()
+            .map(|v| v + 1)
+            .enumerate()
+            .take(NonZeroUsize::new(2).unwrap())
+            .collect_non_empty();
+
+        assert_eq!(calls, 2);
+        assert_eq!(boxed, [(0, 31), (1, 21)]);
+    }
+
+    #[test]
+    fn flat_map() {
+        let records = non_empty_vec![non_empty_vec![1, 2], non_empty_vec![3]];
+
+        let rows: NonEmptyVec<&i32> = records.iter().flat_map(|record| record).collect_non_empty();
+        assert_eq!(rows, non_empty_vec![&1, &2, &3]);
+
+        let expanded: NonEmptyVec<i32> = non_empty_vec![1, 2]
+            .non_empty_into_iter()
+            .flat_map(|v| non_empty_vec![v, v * 10])
+            .collect_non_empty();
+        assert_eq!(expanded, non_empty_vec![1, 10, 2, 20]);
+
+        let rev: NonEmptyVec<i32> = records
+            .iter()
+            .flat_map(|r| r.iter())
+            .copied()
+            .rev()
+            .collect_non_empty();
+        assert_eq!(rev, non_empty_vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn unzip() {
+        let pairs = non_empty_vec![(1, 'a'), (2, 'b')];
+
+        let (numbers, letters) = pairs.iter().copied().unzip();
+        assert_eq!(numbers, non_empty_vec![1, 2]);
+        assert_eq!(letters, non_empty_vec!['a', 'b']);
+
+        let words = non_empty_vec!["x"];
+        let (indices, values) = words.iter().enumerate().unzip();
+        assert_eq!(indices, non_empty_vec![0]);
+        assert_eq!(values, non_empty_vec![&"x"]);
+    }
+
+    #[test]
+    fn cartesian_product() {
+        let rows = non_empty_vec![1, 2];
+        let columns = non_empty_vec!['a', 'b', 'c'];
+
+        let grid = rows.iter().copied().cartesian_product(&columns);
+        assert_eq!(grid.size_hint(), (6, Some(6)));
+
+        let grid: NonEmptyVec<(i32, &char)> = grid.collect_non_empty();
+        assert_eq!(
+            grid,
+            non_empty_vec![
+                (1, &'a'),
+                (1, &'b'),
+                (1, &'c'),
+                (2, &'a'),
+                (2, &'b'),
+                (2, &'c')
+            ]
+        );
+
+        let single = non_empty_vec![1];
+        let one: NonEmptyVec<_> = single.iter().cartesian_product(&rows).collect_non_empty();
+        assert_eq!(one, non_empty_vec![(&1, &1), (&1, &2)]);
+    }
+
+    #[test]
+    fn scan1() {
+        let vec = non_empty_vec![3, 1, 4, 1, 5];
+
+        let sums: NonEmptyVec<i32> = vec
+            .iter()
+            .copied()
+            .scan1(|acc, v| acc + v)
+            .collect_non_empty();
+        assert_eq!(sums, non_empty_vec![3, 4, 8, 9, 14]);
+
+        let maxima = vec.iter().scan1(|acc, v| (*acc).max(v));
+        assert_eq!(maxima.non_zero_len().get(), 5);
+        assert_eq!(maxima.last(), &5);
+    }
+
+    #[test]
+    fn non_empty_iterators_cannot_be_advanced() {
+        trait NotIterator {
+            const IS_ITERATOR: bool = false;
+        }
+
+        struct Probe<T>(std::marker::PhantomData<T>);
+
+        impl<T> NotIterator for Probe<T> {}
+
+        impl<T: Iterator> Probe<T> {
+            const IS_ITERATOR: bool = true;
+        }
+
+        const { assert!(Probe::<std::slice::Iter<'_, i32>>::IS_ITERATOR) };
+        const { assert!(!Probe::<crate::NonEmptyIter<'_, i32>>::IS_ITERATOR) };
+        const { assert!(!Probe::<crate::NonEmptyIterMut<'_, i32>>::IS_ITERATOR) };
+        const { assert!(!Probe::<crate::NonEmptyIntoIter<i32>>::IS_ITERATOR) };
+        const { assert!(!Probe::<NonEmptyCopied<std::slice::Iter<'_, i32>>>::IS_ITERATOR) };
+        const { assert!(!Probe::<NonEmptyPeekable<std::slice::Iter<'_, i32>>>::IS_ITERATOR) };
+        const {
+            assert!(!Probe::<NonEmptyChain<std::slice::Iter<'_, i32>, std::slice::Iter<'_, i32>>>::IS_ITERATOR)
+        };
+
+        let vec = non_empty_vec![1];
+        let mut peekable = vec.iter().peekable();
+        assert_eq!(peekable.peek_first(), &&1);
+        assert_eq!(peekable.max(), &1);
+    }
+}
@@ -1,5 +1,36 @@
+#![cfg_attr(not(test), deny(clippy::indexing_slicing))]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
+pub mod checked;
+mod cmp;
+mod iter;
+mod nested;
+mod non_empty_like;
+mod partition;
+mod same_len;
 mod slice;
+mod sorted;
 mod vec;
 
-pub use slice::NonEmptySlice;
-pub use vec::NonEmptyVec;
+pub use iter::{
+    CartesianProduct, FromNonEmptyIterator, IntoNonEmptyIterator, IteratorExt,
+    NonEmptyCartesianProduct, NonEmptyChain, NonEmptyCloned, NonEmptyCopied, NonEmptyEnumerate,
+    NonEmptyFlatMap, NonEmptyInspect, NonEmptyIterator, NonEmptyMap, NonEmptyPeekable, NonEmptyRev,
+    NonEmptyScan1, NonEmptyStepBy, NonEmptyTake, NonEmptyZip, Scan1,
+};
+pub use nested::{Here, NestedIndex, NestedPath, There};
+pub use non_empty_like::NonEmptyLike;
+pub use partition::{Either, Halves, Partitioned};
+pub use same_len::{SameLen, SameLenMap};
+pub use slice::{
+    NonEmptyChunkBy, NonEmptyChunks, NonEmptyChunksMut, NonEmptyIndices, NonEmptyIter,
+    NonEmptyIterMut, NonEmptySlice, NonEmptySlices, NonEmptySplit, NonEmptyWindows,
+};
+#[cfg(feature = "arc-swap")]
+pub use sorted::ArcSortedVec;
+pub use sorted::{
+    Ascending, Descending, DescendingSortedVec, Interpolation, NonEmptySortedSlice, SortOrder,
+    SortedByCachedKeyVec, SortedByKeyVec, SortedDifference, SortedIntersection, SortedMerge,
+    SortedSet, SortedSlice, SortedSymmetricDifference, SortedUnion, SortedVec, TotalOrder,
+};
+pub use vec::{NonEmptyIntoIter, NonEmptyVec};
@@ -0,0 +1,214 @@
+use core::marker::PhantomData;
+
+use crate::{NonEmptySlice, NonEmptyVec};
+
+pub trait NestedIndex {
+    type Child;
+
+    fn child(&self, index: usize) -> Option<&Self::Child>;
+
+    fn child_mut(&mut self, index: usize) -> Option<&mut Self::Child>;
+
+    fn get_path<L, D>(&self, path: &NonEmptySlice<usize>) -> Option<&L>
+    where
+        Self: NestedPath<L, D>,
+    {
+        self.walk(path.as_slice())
+    }
+
+    fn get_path_mut<L, D>(&mut self, path: &NonEmptySlice<usize>) -> Option<&mut L>
+    where
+        Self: NestedPath<L, D>,
+    {
+        self.walk_mut(path.as_slice())
+    }
+
+    fn get_node(&self, path: &NonEmptySlice<usize>) -> Option<&Self::Child>
+    where
+        Self::Child: NestedIndex<Child = Self::Child>,
+    {
+        let first = self.child(*path.first())?;
+        path.tail()
+            .iter()
+            .try_fold(first, |node, &index| node.child(index))
+    }
+
+    fn get_node_mut(&mut self, path: &NonEmptySlice<usize>) -> Option<&mut Self::Child>
+    where
+        Self::Child: NestedIndex<Child = Self::Child>,
+    {
+        let first = self.child_mut(*path.first())?;
+        path.tail()
+            .iter()
+            .try_fold(first, |node, &index| node.child_mut(index))
+    }
+}
+
+pub struct Here;
+
+pub struct There<D>(PhantomData<D>);
+
+pub trait NestedPath<L, D> {
+    fn walk(&self, path: &[usize]) -> Option<&L>;
+
+    fn walk_mut(&mut self, path: &[usize]) -> Option<&mut L>;
+}
+
+impl<N: NestedIndex> NestedPath<N::Child, Here> for N {
+    fn walk(&self, path: &[usize]) -> Option<&N::Child> {
+        match *path {
+            [index] => self.child(index),
+            _ => None,
+        }
+    }
+
+    fn walk_mut(&mut self, path: &[usize]) -> Option<&mut N::Child> {
+        match *path {
+            [index] => self.child_mut(index),
+            _ => None,
+        }
+    }
+}
+
+impl<N, L, D> NestedPath<L, There<D>> for N
+where
+    N: NestedIndex,
+    N::Child: NestedPath<L, D>,
+{
+    fn walk(&self, path: &[usize]) -> Option<&L> {
+        let (&index, rest) = path.split_first()?;
+        self.child(index)?.walk(rest)
+    }
+
+    fn walk_mut(&mut self, path: &[usize]) -> Option<&mut L> {
+        let (&index, rest) = path.split_first()?;
+        self.child_mut(index)?.walk_mut(rest)
+    }
+}
+
+impl<T> NestedIndex for NonEmptySlice<T> {
+    type Child = T;
+
+    fn child(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    fn child_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
+    }
+}
+
+impl<T> NestedIndex for NonEmptyVec<T> {
+    type Child = T;
+
+    fn child(&self, index: usize) -> Option<&T> {
+        self.as_non_empty_slice().child(index)
+    }
+
+    fn child_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_non_empty_slice_mut().child_mut(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::non_empty_vec;
+
+    #[derive(Debug, PartialEq)]
+    struct Node {
+        name: &'static str,
+        children: Vec<Node>,
+    }
+
+    impl NestedIndex for Node {
+        type Child = Node;
+
+        fn child(&self, index: usize) -> Option<&Node> {
+            self.children.get(index)
+        }
+
+        fn child_mut(&mut self, index: usize) -> Option<&mut Node> {
+            self.children.get_mut(index)
+        }
+    }
+
+    fn leaf(name: &'static str) -> Node {
+        Node {
+            name,
+            children: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn tree_path() {
+        let mut root = Node {
+            name: "root",
+            children: vec![
+                leaf("a"),
+                Node {
+                    name: "b",
+                    children: vec![leaf("b0"), leaf("b1")],
+                },
+            ],
+        };
+
+        let path: &NonEmptySlice<usize> = &non_empty_vec![1, 1];
+        assert_eq!(root.get_node(path).map(|node| node.name), Some("b1"));
+
+        let path: &NonEmptySlice<usize> = &non_empty_vec![0];
+        assert_eq!(root.get_node(path).map(|node| node.name), Some("a"));
+
+        let path: &NonEmptySlice<usize> = &non_empty_vec![0, 0];
+        assert_eq!(root.get_node(path), None);
+
+        let path: &NonEmptySlice<usize> = &non_empty_vec![1, 0];
+        root.get_node_mut(path).unwrap().name = "renamed";
+        assert_eq!(root.children[1].children[0].name, "renamed");
+    }
+
+    #[test]
+    fn nested_non_empty_vec() {
+        let mut grid: NonEmptyVec<NonEmptyVec<i32>> =
+            non_empty_vec![non_empty_vec![1, 2], non_empty_vec![3]];
+
+        assert_eq!(grid.get_path(&non_empty_vec![0, 1]), Some(&2));
+        assert_eq!(grid.get_path(&non_empty_vec![1, 0]), Some(&3));
+        assert_eq!(grid.get_path(&non_empty_vec![1, 1]), None::<&i32>);
+        assert_eq!(grid.get_path(&non_empty_vec![0, 0, 0]), None::<&i32>);
+        assert_eq!(
+            grid.get_path(&non_empty_vec![0]),
+            Some(&non_empty_vec![1, 2])
+        );
+
+        *grid.get_path_mut(&non_empty_vec![1, 0]).unwrap() = 30;
+        assert_eq!(
+            grid,
+            non_empty_vec![non_empty_vec![1, 2], non_empty_vec![30]]
+        );
+    }
+
+    #[test]
+    fn three_levels() {
+        let mut cube: NonEmptyVec<NonEmptyVec<NonEmptyVec<i32>>> = non_empty_vec![
+            non_empty_vec![non_empty_vec![1, 2], non_empty_vec![3]],
+            non_empty_vec![non_empty_vec![4, 5, 6]],
+        ];
+
+        assert_eq!(cube.get_path(&non_empty_vec![0, 0, 1]), Some(&2));
+        assert_eq!(cube.get_path(&non_empty_vec![1, 0, 2]), Some(&6));
+        assert_eq!(
+            cube.get_path(&non_empty_vec![0, 1]),
+            Some(&non_empty_vec![3])
+        );
+
+        assert_eq!(cube.get_path(&non_empty_vec![2, 0, 0]), None::<&i32>);
+        assert_eq!(cube.get_path(&non_empty_vec![0, 2, 0]), None::<&i32>);
+        assert_eq!(cube.get_path(&non_empty_vec![1, 0, 3]), None::<&i32>);
+        assert_eq!(cube.get_path(&non_empty_vec![0, 0, 0, 0]), None::<&i32>);
+
+        *cube.get_path_mut(&non_empty_vec![1, 0, 0]).unwrap() = 40;
+        assert_eq!(cube[1][0], non_empty_vec![40, 5, 6]);
+    }
+}
@@ -0,0 +1,79 @@
+use std::num::NonZeroUsize;
+
+use crate::{slice::NonEmptyIter, NonEmptySlice, NonEmptyVec};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl<T> Sealed for crate::NonEmptySlice<T> {}
+    impl<T> Sealed for crate::NonEmptyVec<T> {}
+    impl<T> Sealed for Box<crate::NonEmptySlice<T>> {}
+    impl<C: Sealed + ?Sized> Sealed for &C {}
+}
+
+pub trait NonEmptyLike<T>: sealed::Sealed {
+    fn as_non_empty_slice(&self) -> &NonEmptySlice<T>;
+
+    fn first(&self) -> &T {
+        self.as_non_empty_slice().first()
+    }
+
+    fn last(&self) -> &T {
+        self.as_non_empty_slice().last()
+    }
+
+    fn iter(&self) -> NonEmptyIter<'_, T> {
+        self.as_non_empty_slice().iter()
+    }
+
+    fn non_zero_len(&self) -> NonZeroUsize {
+        self.as_non_empty_slice().non_zero_len()
+    }
+}
+
+impl<T> NonEmptyLike<T> for NonEmptySlice<T> {
+    fn as_non_empty_slice(&self) -> &NonEmptySlice<T> {
+        self
+    }
+}
+
+impl<T> NonEmptyLike<T> for NonEmptyVec<T> {
+    fn as_non_empty_slice(&self) -> &NonEmptySlice<T> {
+        NonEmptyVec::as_non_empty_slice(self)
+    }
+}
+
+impl<T> NonEmptyLike<T> for Box<NonEmptySlice<T>> {
+    fn as_non_empty_slice(&self) -> &NonEmptySlice<T> {
+        self
+    }
+}
+
+impl<T, C: NonEmptyLike<T> + ?Sized> NonEmptyLike<T> for &C {
+    fn as_non_empty_slice(&self) -> &NonEmptySlice<T> {
+        (**self).as_non_empty_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::non_empty_vec;
+
+    fn describe<C: NonEmptyLike<i32> + ?Sized>(c: &C) -> (i32, i32, usize, i32) {
+        let sum = c.iter().sum();
+        (*c.first(), *c.last(), c.non_zero_len().get(), sum)
+    }
+
+    #[test]
+    fn generic() {
+        let vec = non_empty_vec![1, 2, 3];
+        let slice: &NonEmptySlice<i32> = &vec;
+        let boxed: Box<NonEmptySlice<i32>> = vec.clone().into();
+
+        assert_eq!(describe(&vec), (1, 3, 3, 6));
+        assert_eq!(describe(slice), (1, 3, 3, 6));
+        assert_eq!(describe(&boxed), (1, 3, 3, 6));
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{NonEmptySlice, NonEmptyVec};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Partitioned<L, R> {
+    Left(NonEmptyVec<L>),
+    Right(NonEmptyVec<R>),
+    Both(NonEmptyVec<L>, NonEmptyVec<R>),
+}
+
+impl<L, R> Partitioned<L, R> {
+    // Callers must guarantee that at least one side is non-empty,
+    // which holds whenever the input was non-empty.
+    pub(crate) fn from_vecs(left: Vec<L>, right: Vec<R>) -> Self {
+        match (NonEmptyVec::try_from(left), NonEmptyVec::try_from(right)) {
+            (Ok(left), Ok(right)) => Partitioned::Both(left, right),
+            (Ok(left), Err(_)) => Partitioned::Left(left),
+            (Err(_), Ok(right)) => Partitioned::Right(right),
+            (Err(_), Err(_)) => {
+                unreachable!("partitioned a non-empty collection into two empty sides")
+            }
+        }
+    }
+
+    pub fn left(&self) -> Option<&NonEmptyVec<L>> {
+        match self {
+            Partitioned::Left(left) | Partitioned::Both(left, _) => Some(left),
+            Partitioned::Right(_) => None,
+        }
+    }
+
+    pub fn right(&self) -> Option<&NonEmptyVec<R>> {
+        match self {
+            Partitioned::Right(right) | Partitioned::Both(_, right) => Some(right),
+            Partitioned::Left(_) => None,
+        }
+    }
+
+    pub fn into_vecs(self) -> (Vec<L>, Vec<R>) {
+        match self {
+            Partitioned::Left(left) => (left.into_vec(), Vec::new()),
+            Partitioned::Right(right) => (Vec::new(), right.into_vec()),
+            Partitioned::Both(left, right) => (left.into_vec(), right.into_vec()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Halves<'a, T> {
+    Left(&'a NonEmptySlice<T>),
+    Right(&'a NonEmptySlice<T>),
+    Both(&'a NonEmptySlice<T>, &'a NonEmptySlice<T>),
+}
+
+impl<'a, T> Halves<'a, T> {
+    pub fn left(&self) -> Option<&'a NonEmptySlice<T>> {
+        match *self {
+            Halves::Left(left) | Halves::Both(left, _) => Some(left),
+            Halves::Right(_) => None,
+        }
+    }
+
+    pub fn right(&self) -> Option<&'a NonEmptySlice<T>> {
+        match *self {
+            Halves::Right(right) | Halves::Both(_, right) => Some(right),
+            Halves::Left(_) => None,
+        }
+    }
+
+    pub fn into_slices(self) -> (&'a [T], &'a [T]) {
+        match self {
+            Halves::Left(left) => (left.as_slice(), &[]),
+            Halves::Right(right) => (&[], right.as_slice()),
+            Halves::Both(left, right) => (left.as_slice(), right.as_slice()),
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use std::{iter::Zip, mem, num::NonZeroUsize, slice::Iter};
+
+use crate::{NonEmptySlice, NonEmptyVec};
+
+pub trait SameLenMap: Sized {
+    type Item;
+    type Output<U>;
+
+    fn same_len_map<U, F: FnMut(Self::Item) -> U>(self, f: F) -> Self::Output<U>;
+}
+
+impl<T> SameLenMap for NonEmptyVec<T> {
+    type Item = T;
+    type Output<U> = NonEmptyVec<U>;
+
+    fn same_len_map<U, F: FnMut(T) -> U>(self, f: F) -> NonEmptyVec<U> {
+        let mapped = self.into_vec().into_iter().map(f).collect();
+        // SAFETY: `map` yields exactly one item per element of `self`.
+        unsafe { NonEmptyVec::new_unchecked(mapped) }
+    }
+}
+
+impl<'a, T> SameLenMap for &'a NonEmptySlice<T> {
+    type Item = &'a T;
+    type Output<U> = NonEmptyVec<U>;
+
+    fn same_len_map<U, F: FnMut(&'a T) -> U>(self, f: F) -> NonEmptyVec<U> {
+        let mapped = self.as_slice().iter().map(f).collect();
+        // SAFETY: `map` yields exactly one item per element of `self`.
+        unsafe { NonEmptyVec::new_unchecked(mapped) }
+    }
+}
+
+impl<T> SameLenMap for Box<NonEmptySlice<T>> {
+    type Item = T;
+    type Output<U> = Box<NonEmptySlice<U>>;
+
+    fn same_len_map<U, F: FnMut(T) -> U>(self, f: F) -> Box<NonEmptySlice<U>> {
+        self.into_non_empty_vec().same_len_map(f).into_boxed_slice()
+    }
+}
+
+pub struct SameLen<A, B> {
+    left: NonEmptyVec<A>,
+    right: NonEmptyVec<B>,
+}
+
+mod error {
+    use std::{error::Error, fmt};
+
+    use crate::NonEmptyVec;
+
+    pub struct LengthMismatch<A, B> {
+        pub(super) left: NonEmptyVec<A>,
+        pub(super) right: NonEmptyVec<B>,
+    }
+
+    impl<A, B> LengthMismatch<A, B> {
+        pub fn into_parts(self) -> (NonEmptyVec<A>, NonEmptyVec<B>) {
+            (self.left, self.right)
+        }
+    }
+
+    impl<A, B> fmt::Debug for LengthMismatch<A, B> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("LengthMismatch")
+                .field("left", &self.left.len())
+                .field("right", &self.right.len())
+                .finish()
+        }
+    }
+
+    impl<A, B> fmt::Display for LengthMismatch<A, B> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "length mismatch: {} != {}",
+                self.left.len(),
+                self.right.len()
+            )
+        }
+    }
+
+    impl<A, B> Error for LengthMismatch<A, B> {}
+}
+
+impl<A, B> SameLen<A, B> {
+    pub fn new(
+        left: NonEmptyVec<A>,
+        right: NonEmptyVec<B>,
+    ) -> Result<SameLen<A, B>, error::LengthMismatch<A, B>> {
+        if left.len() == right.len() {
+            Ok(SameLen { left, right })
+        } else {
+            Err(error::LengthMismatch { left, right })
+        }
+    }
+
+    pub fn non_zero_len(&self) -> NonZeroUsize {
+        self.left.non_zero_len()
+    }
+
+    pub fn left(&self) -> &NonEmptyVec<A> {
+        &self.left
+    }
+
+    pub fn right(&self) -> &NonEmptyVec<B> {
+        &self.right
+    }
+
+    pub fn into_parts(self) -> (NonEmptyVec<A>, NonEmptyVec<B>) {
+        (self.left, self.right)
+    }
+
+    pub fn zip(self) -> NonEmptyVec<(A, B)> {
+        self.left.zip(self.right)
+    }
+
+    pub fn index_both(&self, index: usize) -> Option<(&A, &B)> {
+        if index < self.left.len() {
+            // SAFETY: Both sides have the same length, which we just
+            // checked `index` against.
+            Some(unsafe {
+                (
+                    self.left.as_slice().get_unchecked(index),
+                    self.right.as_slice().get_unchecked(index),
+                )
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn zip_exact(&self) -> Zip<Iter<'_, A>, Iter<'_, B>> {
+        self.left.as_slice().iter().zip(self.right.as_slice())
+    }
+
+    pub fn co_sort_by_key<K: Ord, F: FnMut(&A) -> K>(&mut self, f: F) {
+        let mut keyed: Vec<(K, usize)> = self.left.as_slice().iter().map(f).zip(0..).collect();
+        keyed.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut permutation: Vec<usize> = keyed.into_iter().map(|(_, index)| index).collect();
+        apply_permutation(
+            self.left.as_mut_slice(),
+            self.right.as_mut_slice(),
+            &mut permutation,
+        );
+    }
+}
+
+impl<A: Ord, B> SameLen<A, B> {
+    pub fn co_sort(&mut self) {
+        let mut permutation: Vec<usize> = (0..self.left.len()).collect();
+        permutation.sort_by_key(|&index| self.left.get(index));
+        apply_permutation(
+            self.left.as_mut_slice(),
+            self.right.as_mut_slice(),
+            &mut permutation,
+        );
+    }
+}
+
+// Moves the element at `permutation[i]` to position `i` in both slices,
+// following each cycle of the permutation and marking visited positions
+// by overwriting them with their own index.
+fn apply_permutation<A, B>(left: &mut [A], right: &mut [B], permutation: &mut [usize]) {
+    for start in 0..permutation.len() {
+        let mut current = start;
+        while let Some(slot) = permutation.get_mut(current) {
+            let next = mem::replace(slot, current);
+            if next == current || next == start {
+                break;
+            }
+            left.swap(current, next);
+            right.swap(current, next);
+            current = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::non_empty_vec;
+
+    #[test]
+    fn new() {
+        let same_len = SameLen::new(non_empty_vec![1, 2], non_empty_vec!["a", "b"]).unwrap();
+
+        assert_eq!(same_len.non_zero_len().get(), 2);
+        assert_eq!(same_len.index_both(1), Some((&2, &"b")));
+        assert_eq!(same_len.index_both(2), None);
+
+        let error = match SameLen::new(non_empty_vec![1, 2], non_empty_vec!["a"]) {
+            Ok(_) => panic!("lengths differ"),
+            Err(error) => error,
+        };
+        assert_eq!(error.to_string(), "length mismatch: 2 != 1");
+        assert_eq!(
+            error.into_parts(),
+            (non_empty_vec![1, 2], non_empty_vec!["a"])
+        );
+    }
+
+    #[test]
+    fn same_len_map() {
+        fn double<C: SameLenMap<Item = i32>>(c: C) -> C::Output<i32> {
+            c.same_len_map(|v| v * 2)
+        }
+
+        assert_eq!(double(non_empty_vec![1, 2, 3]), non_empty_vec![2, 4, 6]);
+
+        let boxed: Box<NonEmptySlice<i32>> = non_empty_vec![1, 2].into();
+        assert_eq!(double(boxed).as_slice(), &[2, 4]);
+
+        let slice: &NonEmptySlice<i32> = &non_empty_vec![1, 2];
+        assert_eq!(slice.same_len_map(|v| v + 1), non_empty_vec![2, 3]);
+
+        let same_len = SameLen::new(non_empty_vec![1, 2], non_empty_vec!["a", "b"]).unwrap();
+        assert_eq!(same_len.zip(), non_empty_vec![(1, "a"), (2, "b")]);
+    }
+
+    #[test]
+    fn zip_exact() {
+        let same_len =
+            SameLen::new(non_empty_vec![1, 2, 3], non_empty_vec!["a", "b", "c"]).unwrap();
+
+        let zipped = same_len.zip_exact();
+
+        assert_eq!(zipped.len(), 3);
+        assert_eq!(
+            zipped.collect::<Vec<_>>(),
+            vec![(&1, &"a"), (&2, &"b"), (&3, &"c")]
+        );
+    }
+
+    #[test]
+    fn co_sort() {
+        let mut same_len = SameLen::new(
+            non_empty_vec![3, 1, 4, 5, 2],
+            non_empty_vec!["c", "a", "d", "e", "b"],
+        )
+        .unwrap();
+
+        same_len.co_sort();
+
+        assert_eq!(
+            same_len.into_parts(),
+            (
+                non_empty_vec![1, 2, 3, 4, 5],
+                non_empty_vec!["a", "b", "c", "d", "e"]
+            )
+        );
+
+        let mut same_len =
+            SameLen::new(non_empty_vec![1, 2, 3], non_empty_vec!["a", "b", "c"]).unwrap();
+
+        same_len.co_sort_by_key(|&v| std::cmp::Reverse(v));
+
+        assert_eq!(same_len.left(), &non_empty_vec![3, 2, 1]);
+        assert_eq!(same_len.right(), &non_empty_vec!["c", "b", "a"]);
+    }
+}
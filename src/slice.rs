@@ -1,11 +1,25 @@
 mod iter;
 
-use std::{fmt, num::NonZeroUsize, ops::Deref};
+use std::{
+    array,
+    cmp::Ordering,
+    fmt,
+    iter::{Cycle, Zip},
+    num::NonZeroUsize,
+    ops::Deref,
+    slice::{GetDisjointMutError, Iter, SliceIndex, Windows},
+};
 
-use super::NonEmptyVec;
-pub use iter::NonEmptyIter;
+use super::{
+    Either, Halves, NonEmptyLike, NonEmptySortedSlice, NonEmptyVec, Partitioned, SameLenMap,
+    SortedSlice,
+};
+pub use iter::{
+    NonEmptyChunkBy, NonEmptyChunks, NonEmptyChunksMut, NonEmptyIndices, NonEmptyIter,
+    NonEmptyIterMut, NonEmptySlices, NonEmptySplit, NonEmptyWindows,
+};
 
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct NonEmptySlice<T> {
     inner: [T],
@@ -24,6 +38,17 @@ mod error {
     }
 
     impl Error for Empty {}
+
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct SplitError;
+
+    impl fmt::Display for SplitError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "split would leave an empty half")
+        }
+    }
+
+    impl Error for SplitError {}
 }
 
 impl<T> NonEmptySlice<T> {
@@ -35,6 +60,30 @@ impl<T> NonEmptySlice<T> {
         }
     }
 
+    pub const fn from_ref(value: &T) -> &NonEmptySlice<T> {
+        // SAFETY: A single element is a non-empty slice.
+        unsafe { NonEmptySlice::new_unchecked(std::slice::from_ref(value)) }
+    }
+
+    pub const fn from_array<const N: usize>(array: &[T; N]) -> &NonEmptySlice<T> {
+        const { assert!(N > 0, "cannot build a NonEmptySlice from an empty array") };
+        // SAFETY: `N` is checked to be non-zero at compile time.
+        unsafe { NonEmptySlice::new_unchecked(array.as_slice()) }
+    }
+
+    pub fn from_mut(value: &mut T) -> &mut NonEmptySlice<T> {
+        // SAFETY: A single element is a non-empty slice.
+        unsafe { NonEmptySlice::new_unchecked_mut(std::slice::from_mut(value)) }
+    }
+
+    pub fn try_from_mut_slice(slice: &mut [T]) -> Result<&mut NonEmptySlice<T>, error::Empty> {
+        if !slice.is_empty() {
+            Ok(unsafe { NonEmptySlice::new_unchecked_mut(slice) })
+        } else {
+            Err(error::Empty)
+        }
+    }
+
     pub(super) const unsafe fn new_unchecked(slice: &[T]) -> &NonEmptySlice<T> {
         debug_assert!(!slice.is_empty());
         // SAFETY: This type is `repr(transparent)`, so we can safely
@@ -59,24 +108,110 @@ impl<T> NonEmptySlice<T> {
         Box::from_raw(ptr)
     }
 
+    /// # Safety
+    ///
+    /// All the safety requirements of [`std::slice::from_raw_parts`] apply.
+    /// `len` being non-zero means `data` must point to at least one
+    /// initialized element.
+    pub unsafe fn from_raw_parts<'a>(data: *const T, len: NonZeroUsize) -> &'a NonEmptySlice<T> {
+        // SAFETY: The caller upholds the `from_raw_parts` contract and `len`
+        // is non-zero.
+        unsafe { NonEmptySlice::new_unchecked(std::slice::from_raw_parts(data, len.get())) }
+    }
+
+    /// # Safety
+    ///
+    /// All the safety requirements of [`std::slice::from_raw_parts_mut`]
+    /// apply. `len` being non-zero means `data` must point to at least one
+    /// initialized element.
+    pub unsafe fn from_raw_parts_mut<'a>(
+        data: *mut T,
+        len: NonZeroUsize,
+    ) -> &'a mut NonEmptySlice<T> {
+        // SAFETY: The caller upholds the `from_raw_parts_mut` contract and
+        // `len` is non-zero.
+        unsafe { NonEmptySlice::new_unchecked_mut(std::slice::from_raw_parts_mut(data, len.get())) }
+    }
+
+    pub fn boxed_singleton(value: T) -> Box<Self> {
+        NonEmptyVec::one(value).into_boxed_slice()
+    }
+
+    pub fn into_boxed_slice(self: Box<Self>) -> Box<[T]> {
+        // SAFETY: This type is `repr(transparent)`, so we can safely
+        // cast the pointers like this.
+        let ptr = Box::into_raw(self) as *mut [T];
+        unsafe { Box::from_raw(ptr) }
+    }
+
+    pub fn leak<'a>(self: Box<Self>) -> &'a mut NonEmptySlice<T>
+    where
+        T: 'a,
+    {
+        Box::leak(self)
+    }
+
+    pub fn into_non_empty_vec(self: Box<Self>) -> NonEmptyVec<T> {
+        // SAFETY: The boxed slice is non-empty.
+        unsafe { NonEmptyVec::new_unchecked(self.into_boxed_slice().into_vec()) }
+    }
+
+    pub fn into_first(self: Box<Self>) -> T {
+        let mut iter = self.into_boxed_slice().into_vec().into_iter();
+        // SAFETY: The boxed slice is non-empty.
+        unsafe { iter.next().unwrap_unchecked() }
+    }
+
+    pub fn into_last(self: Box<Self>) -> T {
+        let mut vec = self.into_boxed_slice().into_vec();
+        // SAFETY: The boxed slice is non-empty.
+        unsafe { vec.pop().unwrap_unchecked() }
+    }
+
     pub fn non_zero_len(&self) -> NonZeroUsize {
-        self.inner.len().try_into().unwrap()
+        // SAFETY: A `NonEmptySlice` always holds at least one element.
+        unsafe { NonZeroUsize::new_unchecked(self.inner.len()) }
     }
 
     pub fn first(&self) -> &T {
-        &self.inner[0]
+        // SAFETY: A `NonEmptySlice` always holds at least one element.
+        unsafe { self.inner.get_unchecked(0) }
     }
 
     pub fn tail(&self) -> &[T] {
-        &self.inner[1..]
+        // SAFETY: A `NonEmptySlice` always holds at least one element.
+        unsafe { self.inner.get_unchecked(1..) }
     }
 
     pub fn last(&self) -> &T {
-        &self.inner[self.len() - 1]
+        // SAFETY: A `NonEmptySlice` always holds at least one element.
+        unsafe { self.inner.get_unchecked(self.inner.len() - 1) }
     }
 
     pub fn init(&self) -> &[T] {
-        &self.inner[..self.len() - 1]
+        // SAFETY: A `NonEmptySlice` always holds at least one element.
+        unsafe { self.inner.get_unchecked(..self.inner.len() - 1) }
+    }
+
+    pub fn tail_non_empty(&self) -> Option<&NonEmptySlice<T>> {
+        NonEmptySlice::try_from_slice(self.tail()).ok()
+    }
+
+    pub fn init_non_empty(&self) -> Option<&NonEmptySlice<T>> {
+        NonEmptySlice::try_from_slice(self.init()).ok()
+    }
+
+    pub fn first_n(&self, n: NonZeroUsize) -> Option<&NonEmptySlice<T>> {
+        // SAFETY: `n` is non-zero, so a prefix of that length is non-empty.
+        self.inner
+            .get(..n.get())
+            .map(|prefix| unsafe { NonEmptySlice::new_unchecked(prefix) })
+    }
+
+    pub fn last_n(&self, n: NonZeroUsize) -> Option<&NonEmptySlice<T>> {
+        let start = self.inner.len().checked_sub(n.get())?;
+        // SAFETY: `start` is at most `len - 1`, so the suffix is non-empty.
+        Some(unsafe { NonEmptySlice::new_unchecked(self.inner.get_unchecked(start..)) })
     }
 
     pub fn split_first(&self) -> (&T, &[T]) {
@@ -87,22 +222,640 @@ impl<T> NonEmptySlice<T> {
         (self.init(), self.last())
     }
 
+    pub fn first_array(&self) -> &[T; 1] {
+        array::from_ref(self.first())
+    }
+
+    pub fn last_array(&self) -> &[T; 1] {
+        array::from_ref(self.last())
+    }
+
+    pub fn split_first_array(&self) -> (&[T; 1], &[T]) {
+        (self.first_array(), self.tail())
+    }
+
+    pub fn split_last_array(&self) -> (&[T], &[T; 1]) {
+        (self.init(), self.last_array())
+    }
+
+    pub fn first_mut(&mut self) -> &mut T {
+        // SAFETY: A `NonEmptySlice` always holds at least one element.
+        unsafe { self.inner.get_unchecked_mut(0) }
+    }
+
+    pub fn tail_mut(&mut self) -> &mut [T] {
+        // SAFETY: A `NonEmptySlice` always holds at least one element.
+        unsafe { self.inner.get_unchecked_mut(1..) }
+    }
+
+    pub fn last_mut(&mut self) -> &mut T {
+        let last = self.inner.len() - 1;
+        // SAFETY: A `NonEmptySlice` always holds at least one element.
+        unsafe { self.inner.get_unchecked_mut(last) }
+    }
+
+    pub fn init_mut(&mut self) -> &mut [T] {
+        let last = self.inner.len() - 1;
+        // SAFETY: A `NonEmptySlice` always holds at least one element.
+        unsafe { self.inner.get_unchecked_mut(..last) }
+    }
+
+    pub fn split_first_mut(&mut self) -> (&mut T, &mut [T]) {
+        // SAFETY: A `NonEmptySlice` always holds at least one element.
+        unsafe { self.inner.split_first_mut().unwrap_unchecked() }
+    }
+
+    pub fn split_last_mut(&mut self) -> (&mut [T], &mut T) {
+        // SAFETY: A `NonEmptySlice` always holds at least one element.
+        let (last, init) = unsafe { self.inner.split_last_mut().unwrap_unchecked() };
+        (init, last)
+    }
+
     pub fn as_slice(&self) -> &[T] {
         &self.inner
     }
 
+    /// # Safety
+    ///
+    /// The slice must be sorted in ascending order, as for
+    /// [`SortedSlice::new_unchecked`].
+    pub unsafe fn assume_sorted(&self) -> &NonEmptySortedSlice<T> {
+        // SAFETY: The caller upholds the sortedness contract, and a
+        // `NonEmptySlice` always holds at least one element.
+        unsafe {
+            NonEmptySortedSlice::from_sorted_unchecked(SortedSlice::new_unchecked(&self.inner))
+        }
+    }
+
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.inner
+    }
+
+    pub const fn as_ptr(&self) -> *const T {
+        self.inner.as_ptr()
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.inner.as_mut_ptr()
+    }
+
+    pub fn non_empty_index<R>(&self, range: R) -> Option<&NonEmptySlice<T>>
+    where
+        R: SliceIndex<[T], Output = [T]>,
+    {
+        self.inner
+            .get(range)
+            .and_then(|slice| NonEmptySlice::try_from_slice(slice).ok())
+    }
+
+    pub fn non_empty_index_mut<R>(&mut self, range: R) -> Option<&mut NonEmptySlice<T>>
+    where
+        R: SliceIndex<[T], Output = [T]>,
+    {
+        self.inner
+            .get_mut(range)
+            .and_then(|slice| NonEmptySlice::try_from_mut_slice(slice).ok())
+    }
+
+    /// # Safety
+    ///
+    /// `index` must be in bounds, as for [`slice::get_unchecked_mut`].
+    pub unsafe fn get_unchecked_mut<I>(&mut self, index: I) -> &mut I::Output
+    where
+        I: SliceIndex<[T]>,
+    {
+        // SAFETY: The caller guarantees the index is in bounds.
+        unsafe { self.inner.get_unchecked_mut(index) }
+    }
+
+    /// # Safety
+    ///
+    /// `range` must be in bounds, as for [`slice::get_unchecked`], and must
+    /// select at least one element.
+    pub unsafe fn slice_unchecked<R>(&self, range: R) -> &NonEmptySlice<T>
+    where
+        R: SliceIndex<[T], Output = [T]>,
+    {
+        // SAFETY: The caller guarantees the range is in bounds and non-empty.
+        unsafe { NonEmptySlice::new_unchecked(self.inner.get_unchecked(range)) }
+    }
+
+    /// # Safety
+    ///
+    /// Same as [`NonEmptySlice::slice_unchecked`].
+    pub unsafe fn slice_unchecked_mut<R>(&mut self, range: R) -> &mut NonEmptySlice<T>
+    where
+        R: SliceIndex<[T], Output = [T]>,
+    {
+        // SAFETY: The caller guarantees the range is in bounds and non-empty.
+        unsafe { NonEmptySlice::new_unchecked_mut(self.inner.get_unchecked_mut(range)) }
+    }
+
+    pub fn chunks(&self, size: NonZeroUsize) -> NonEmptyChunks<'_, T> {
+        NonEmptyChunks::new(self.inner.chunks(size.get()))
+    }
+
+    pub fn chunk_by<F>(&self, same_group: F) -> NonEmptyChunkBy<'_, T, F>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        NonEmptyChunkBy::new(self.inner.chunk_by(same_group))
+    }
+
+    pub fn split_non_empty<F>(&self, pred: F) -> NonEmptySplit<'_, T, F>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        NonEmptySplit::new(self.inner.split(pred))
+    }
+
+    pub fn windows(&self, size: NonZeroUsize) -> NonEmptySlices<Windows<'_, T>> {
+        // SAFETY: `windows` requires a non-zero size and never yields empty
+        // windows.
+        unsafe { NonEmptySlices::new_unchecked(self.inner.windows(size.get())) }
+    }
+
+    pub fn windows_non_empty(&self, size: NonZeroUsize) -> Option<NonEmptyWindows<'_, T>> {
+        NonEmptyWindows::new(self.inner.windows(size.get()))
+    }
+
+    pub fn split_at(&self, mid: NonZeroUsize) -> (&NonEmptySlice<T>, &[T]) {
+        let (left, right) = self.inner.split_at(mid.get());
+        // SAFETY: `mid` is non-zero, so the left half holds at least one element.
+        (unsafe { NonEmptySlice::new_unchecked(left) }, right)
+    }
+
+    pub fn split_at_non_empty(
+        &self,
+        mid: NonZeroUsize,
+    ) -> Result<(&NonEmptySlice<T>, &NonEmptySlice<T>), error::SplitError> {
+        if mid.get() < self.inner.len() {
+            let (left, right) = self.split_at(mid);
+            // SAFETY: `mid` is less than the length, so the right half
+            // holds at least one element.
+            Ok((left, unsafe { NonEmptySlice::new_unchecked(right) }))
+        } else {
+            Err(error::SplitError)
+        }
+    }
+
+    pub fn split_at_mut(&mut self, mid: NonZeroUsize) -> (&mut NonEmptySlice<T>, &mut [T]) {
+        let (left, right) = self.inner.split_at_mut(mid.get());
+        // SAFETY: `mid` is non-zero, so the left half holds at least one element.
+        (unsafe { NonEmptySlice::new_unchecked_mut(left) }, right)
+    }
+
+    pub fn split_at_mut_non_empty(
+        &mut self,
+        mid: NonZeroUsize,
+    ) -> Result<(&mut NonEmptySlice<T>, &mut NonEmptySlice<T>), error::SplitError> {
+        if mid.get() < self.inner.len() {
+            let (left, right) = self.split_at_mut(mid);
+            // SAFETY: `mid` is less than the length, so the right half
+            // holds at least one element.
+            Ok((left, unsafe { NonEmptySlice::new_unchecked_mut(right) }))
+        } else {
+            Err(error::SplitError)
+        }
+    }
+
+    pub fn chunks_mut(&mut self, size: NonZeroUsize) -> NonEmptyChunksMut<'_, T> {
+        NonEmptyChunksMut::new(self.inner.chunks_mut(size.get()))
+    }
+
+    pub fn get_many_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<[&mut T; N], GetDisjointMutError> {
+        self.inner.get_disjoint_mut(indices)
+    }
+
+    pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        self.inner.binary_search_by(f)
+    }
+
+    pub fn binary_search_by_key<B, F>(&self, b: &B, f: F) -> Result<usize, usize>
+    where
+        B: Ord,
+        F: FnMut(&T) -> B,
+    {
+        self.inner.binary_search_by_key(b, f)
+    }
+
+    pub fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.inner.partition_point(pred)
+    }
+
+    pub fn split_at_partition_point<P>(&self, pred: P) -> Halves<'_, T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let Some(mid) = NonZeroUsize::new(self.partition_point(pred)) else {
+            return Halves::Right(self);
+        };
+        match self.split_at_non_empty(mid) {
+            Ok((left, right)) => Halves::Both(left, right),
+            Err(_) => Halves::Left(self),
+        }
+    }
+
+    pub fn select_nth_unstable_by<F>(
+        &mut self,
+        index: usize,
+        compare: F,
+    ) -> (&mut [T], &mut T, &mut [T])
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.inner.select_nth_unstable_by(index, compare)
+    }
+
+    pub fn select_nth_unstable_by_key<K, F>(
+        &mut self,
+        index: usize,
+        f: F,
+    ) -> (&mut [T], &mut T, &mut [T])
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.inner.select_nth_unstable_by_key(index, f)
+    }
+
     pub fn reverse(&mut self) {
         self.inner.reverse()
     }
 
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {
+        self.inner.sort_by(compare)
+    }
+
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, f: F) {
+        self.inner.sort_by_key(f)
+    }
+
+    pub fn sort_by_cached_key<K: Ord, F: FnMut(&T) -> K>(&mut self, f: F) {
+        self.inner.sort_by_cached_key(f)
+    }
+
+    pub fn sort_unstable_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {
+        self.inner.sort_unstable_by(compare)
+    }
+
+    pub fn sort_unstable_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, f: F) {
+        self.inner.sort_unstable_by_key(f)
+    }
+
     pub fn iter(&self) -> NonEmptyIter<'_, T> {
         NonEmptyIter::new_unchecked(self.inner.iter())
     }
+
+    pub fn iter_mut(&mut self) -> NonEmptyIterMut<'_, T> {
+        NonEmptyIterMut::new_unchecked(self.inner.iter_mut())
+    }
+
+    pub fn cycle(&self) -> Cycle<Iter<'_, T>> {
+        self.inner.iter().cycle()
+    }
+
+    pub fn indices(&self) -> NonEmptyIndices {
+        NonEmptyIndices::new_unchecked(0..self.inner.len())
+    }
+
+    pub fn zip_indices(&self) -> Zip<NonEmptyIndices, Iter<'_, T>> {
+        self.indices().zip(&self.inner)
+    }
+
+    pub fn max_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> &T {
+        self.tail()
+            .iter()
+            .fold(self.first(), |max, value| match compare(max, value) {
+                Ordering::Greater => max,
+                _ => value,
+            })
+    }
+
+    pub fn min_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> &T {
+        self.tail()
+            .iter()
+            .fold(self.first(), |min, value| match compare(min, value) {
+                Ordering::Greater => value,
+                _ => min,
+            })
+    }
+
+    pub fn minmax_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> (&T, &T) {
+        self.tail()
+            .iter()
+            .fold((self.first(), self.first()), |(min, max), value| {
+                let min = match compare(min, value) {
+                    Ordering::Greater => value,
+                    _ => min,
+                };
+                let max = match compare(max, value) {
+                    Ordering::Greater => max,
+                    _ => value,
+                };
+                (min, max)
+            })
+    }
+
+    pub fn max_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> &T {
+        let first = (f(self.first()), self.first());
+        let (_, max) = self.tail().iter().fold(first, |(max_key, max), value| {
+            let key = f(value);
+            if key >= max_key {
+                (key, value)
+            } else {
+                (max_key, max)
+            }
+        });
+        max
+    }
+
+    pub fn min_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> &T {
+        let first = (f(self.first()), self.first());
+        let (_, min) = self.tail().iter().fold(first, |(min_key, min), value| {
+            let key = f(value);
+            if key < min_key {
+                (key, value)
+            } else {
+                (min_key, min)
+            }
+        });
+        min
+    }
+
+    pub fn position_max_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> usize {
+        let first = (self.first(), 0);
+        let (_, position) =
+            self.tail()
+                .iter()
+                .zip(1..)
+                .fold(
+                    first,
+                    |(max, max_position), (value, position)| match compare(max, value) {
+                        Ordering::Greater => (max, max_position),
+                        _ => (value, position),
+                    },
+                );
+        position
+    }
+
+    pub fn position_min_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> usize {
+        let first = (self.first(), 0);
+        let (_, position) =
+            self.tail()
+                .iter()
+                .zip(1..)
+                .fold(
+                    first,
+                    |(min, min_position), (value, position)| match compare(min, value) {
+                        Ordering::Greater => (value, position),
+                        _ => (min, min_position),
+                    },
+                );
+        position
+    }
+
+    pub fn position_max_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> usize {
+        let first = (f(self.first()), 0);
+        let (_, position) = self.tail().iter().zip(1..).fold(
+            first,
+            |(max_key, max_position), (value, position)| {
+                let key = f(value);
+                if key >= max_key {
+                    (key, position)
+                } else {
+                    (max_key, max_position)
+                }
+            },
+        );
+        position
+    }
+
+    pub fn position_min_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> usize {
+        let first = (f(self.first()), 0);
+        let (_, position) = self.tail().iter().zip(1..).fold(
+            first,
+            |(min_key, min_position), (value, position)| {
+                let key = f(value);
+                if key < min_key {
+                    (key, position)
+                } else {
+                    (min_key, min_position)
+                }
+            },
+        );
+        position
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> NonEmptyVec<U> {
+        self.same_len_map(f)
+    }
+
+    pub fn concat<U: Clone>(&self) -> NonEmptyVec<U>
+    where
+        T: NonEmptyLike<U>,
+    {
+        let len = self
+            .inner
+            .iter()
+            .map(|piece| piece.non_zero_len().get())
+            .sum();
+        let mut result = NonEmptyVec::with_capacity(piece_first(self.first()), len);
+        result.extend_from_slice(self.first().as_non_empty_slice().tail());
+        for piece in self.tail() {
+            result.extend_from_slice(piece.as_non_empty_slice());
+        }
+        result
+    }
+
+    pub fn join<U: Clone>(&self, separator: &U) -> NonEmptyVec<U>
+    where
+        T: NonEmptyLike<U>,
+    {
+        let len = self
+            .inner
+            .iter()
+            .map(|piece| piece.non_zero_len().get() + 1)
+            .sum::<usize>()
+            - 1;
+        let mut result = NonEmptyVec::with_capacity(piece_first(self.first()), len);
+        result.extend_from_slice(self.first().as_non_empty_slice().tail());
+        for piece in self.tail() {
+            result.push(separator.clone());
+            result.extend_from_slice(piece.as_non_empty_slice());
+        }
+        result
+    }
+
+    pub fn partition_map<A, B, F>(&self, mut f: F) -> (Vec<A>, Vec<B>)
+    where
+        F: FnMut(&T) -> Either<A, B>,
+    {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        for value in &self.inner {
+            match f(value) {
+                Either::Left(a) => left.push(a),
+                Either::Right(b) => right.push(b),
+            }
+        }
+        (left, right)
+    }
+
+    pub fn partition_map_non_empty<A, B, F>(&self, f: F) -> Partitioned<A, B>
+    where
+        F: FnMut(&T) -> Either<A, B>,
+    {
+        let (left, right) = self.partition_map(f);
+        Partitioned::from_vecs(left, right)
+    }
+}
+
+fn piece_first<T: Clone, C: NonEmptyLike<T> + ?Sized>(piece: &C) -> T {
+    piece.as_non_empty_slice().first().clone()
 }
 
 impl<T: Clone> NonEmptySlice<T> {
+    pub fn fold_first<F: FnMut(T, &T) -> T>(&self, f: F) -> T {
+        self.tail().iter().fold(self.first().clone(), f)
+    }
+
     pub fn to_non_empty_vec(&self) -> NonEmptyVec<T> {
-        self.inner.to_vec().try_into().unwrap()
+        // SAFETY: Copying a non-empty slice yields a non-empty vector.
+        unsafe { NonEmptyVec::new_unchecked(self.inner.to_vec()) }
+    }
+}
+
+impl<T: PartialEq> NonEmptySlice<T> {
+    pub fn run_lengths(&self) -> NonEmptyVec<(&T, NonZeroUsize)> {
+        let mut runs = NonEmptyVec::one((self.first(), NonZeroUsize::MIN));
+        for value in self.tail() {
+            let (last, count) = runs.last_mut();
+            if *last == value {
+                *count = count.saturating_add(1);
+            } else {
+                runs.push((value, NonZeroUsize::MIN));
+            }
+        }
+        runs
+    }
+
+    pub fn starts_with(&self, needle: &[T]) -> bool {
+        self.inner.starts_with(needle)
+    }
+
+    pub fn ends_with(&self, needle: &[T]) -> bool {
+        self.inner.ends_with(needle)
+    }
+
+    pub fn strip_prefix(&self, prefix: &[T]) -> Option<&[T]> {
+        self.inner.strip_prefix(prefix)
+    }
+
+    pub fn strip_suffix(&self, suffix: &[T]) -> Option<&[T]> {
+        self.inner.strip_suffix(suffix)
+    }
+
+    pub fn strip_prefix_non_empty(&self, prefix: &[T]) -> Option<&NonEmptySlice<T>> {
+        self.strip_prefix(prefix)
+            .and_then(|rest| NonEmptySlice::try_from_slice(rest).ok())
+    }
+
+    pub fn strip_suffix_non_empty(&self, suffix: &[T]) -> Option<&NonEmptySlice<T>> {
+        self.strip_suffix(suffix)
+            .and_then(|rest| NonEmptySlice::try_from_slice(rest).ok())
+    }
+}
+
+impl<T: Ord> NonEmptySlice<T> {
+    pub fn max(&self) -> &T {
+        self.max_by(Ord::cmp)
+    }
+
+    pub fn min(&self) -> &T {
+        self.min_by(Ord::cmp)
+    }
+
+    pub fn minmax(&self) -> (&T, &T) {
+        self.minmax_by(Ord::cmp)
+    }
+
+    pub fn as_sorted(&self) -> Option<&NonEmptySortedSlice<T>> {
+        let sorted = SortedSlice::try_from_slice(&self.inner).ok()?;
+        // SAFETY: A `NonEmptySlice` always holds at least one element.
+        Some(unsafe { NonEmptySortedSlice::from_sorted_unchecked(sorted) })
+    }
+
+    pub fn sort(&mut self) {
+        self.inner.sort()
+    }
+
+    pub fn sort_and_view(&mut self) -> &NonEmptySortedSlice<T> {
+        self.inner.sort();
+        // SAFETY: The slice was just sorted.
+        unsafe { self.assume_sorted() }
+    }
+
+    pub fn sort_unstable(&mut self) {
+        self.inner.sort_unstable()
+    }
+
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize> {
+        self.binary_search_by(|probe| probe.cmp(x))
+    }
+
+    pub fn select_nth_unstable(&mut self, index: usize) -> (&mut [T], &mut T, &mut [T]) {
+        self.inner.select_nth_unstable(index)
+    }
+
+    pub fn position_max(&self) -> usize {
+        self.position_max_by(Ord::cmp)
+    }
+
+    pub fn position_min(&self) -> usize {
+        self.position_min_by(Ord::cmp)
+    }
+}
+
+impl<T: Clone> NonEmptySlice<T> {
+    pub fn cumulative<F: FnMut(&T, &T) -> T>(&self, mut f: F) -> NonEmptyVec<T> {
+        let mut previous: Option<T> = None;
+        self.same_len_map(|value| {
+            let current = match previous.take() {
+                Some(previous) => f(&previous, value),
+                None => value.clone(),
+            };
+            previous = Some(current.clone());
+            current
+        })
+    }
+}
+
+impl<T: Copy> NonEmptySlice<T> {
+    pub fn repeat_non_zero(&self, n: NonZeroUsize) -> NonEmptyVec<T> {
+        // SAFETY: Repeating a non-empty slice a non-zero number of times
+        // yields a non-empty vector.
+        unsafe { NonEmptyVec::new_unchecked(self.inner.repeat(n.get())) }
+    }
+}
+
+impl<T: fmt::Display> NonEmptySlice<T> {
+    pub fn join_display(&self, separator: &str) -> String {
+        let mut result = self.first().to_string();
+        for item in self.tail() {
+            result.push_str(separator);
+            result.push_str(&item.to_string());
+        }
+        result
     }
 }
 
@@ -127,6 +880,21 @@ impl<'a, T> IntoIterator for &'a NonEmptySlice<T> {
     }
 }
 
+impl<'a, T> IntoIterator for &'a mut NonEmptySlice<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter_mut()
+    }
+}
+
+impl<T> AsRef<[T]> for NonEmptySlice<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.inner
+    }
+}
+
 impl<T> Deref for NonEmptySlice<T> {
     type Target = [T];
 
@@ -145,6 +913,24 @@ impl<'a, T> TryFrom<&'a [T]> for &'a NonEmptySlice<T> {
     }
 }
 
+impl<'a, T> TryFrom<&'a mut [T]> for &'a mut NonEmptySlice<T> {
+    type Error = error::Empty;
+
+    #[inline]
+    fn try_from(value: &'a mut [T]) -> Result<Self, Self::Error> {
+        NonEmptySlice::try_from_mut_slice(value)
+    }
+}
+
+impl<'a, T> TryFrom<&'a mut Vec<T>> for &'a mut NonEmptySlice<T> {
+    type Error = error::Empty;
+
+    #[inline]
+    fn try_from(value: &'a mut Vec<T>) -> Result<Self, Self::Error> {
+        NonEmptySlice::try_from_mut_slice(value)
+    }
+}
+
 impl<T> TryFrom<Box<[T]>> for Box<NonEmptySlice<T>> {
     type Error = error::Empty;
 
@@ -159,6 +945,13 @@ impl<T> TryFrom<Box<[T]>> for Box<NonEmptySlice<T>> {
     }
 }
 
+#[macro_export]
+macro_rules! non_empty_slice {
+    ($($x:expr),+ $(,)?) => {
+        const { $crate::NonEmptySlice::from_array(&[$($x),+]) }
+    };
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -172,6 +965,18 @@ mod tests {
         assert_eq!(non_empty_slice.as_slice(), &[10, 20, 30, 40, 50])
     }
 
+    #[test]
+    fn from_ref() {
+        const ONE: &NonEmptySlice<i32> = NonEmptySlice::from_ref(&10);
+        assert_eq!(ONE.as_slice(), &[10]);
+
+        let mut value = 10;
+        NonEmptySlice::from_mut(&mut value)
+            .first_mut()
+            .clone_from(&20);
+        assert_eq!(value, 20);
+    }
+
     #[test]
     fn debug() {
         let multiple: &NonEmptySlice<i32> = &non_empty_vec![10, 20, 30, 40, 50];
@@ -188,6 +993,199 @@ mod tests {
         assert_eq!(multiple.split_last(), (&[10, 20, 30, 40][..], &50));
     }
 
+    #[test]
+    fn split_mut() {
+        let multiple: &mut NonEmptySlice<_> = &mut non_empty_vec![10, 20, 30, 40, 50];
+
+        *multiple.first_mut() += 1;
+        *multiple.last_mut() += 1;
+        multiple.tail_mut()[0] += 1;
+        multiple.init_mut()[3] += 1;
+        assert_eq!(multiple.as_slice(), &[11, 21, 30, 41, 51]);
+
+        let (first, tail) = multiple.split_first_mut();
+        *first = tail.len();
+        let (init, last) = multiple.split_last_mut();
+        *last = init.len();
+        assert_eq!(multiple.as_slice(), &[4, 21, 30, 41, 4]);
+    }
+
+    #[test]
+    fn get_many_mut() {
+        let multiple: &mut NonEmptySlice<_> = &mut non_empty_vec![10, 20, 30];
+
+        let [a, b] = multiple.get_many_mut([0, 2]).unwrap();
+        std::mem::swap(a, b);
+        assert_eq!(multiple.as_slice(), &[30, 20, 10]);
+
+        assert_eq!(
+            multiple.get_many_mut([1, 1]),
+            Err(GetDisjointMutError::OverlappingIndices)
+        );
+        assert_eq!(
+            multiple.get_many_mut([0, 3]),
+            Err(GetDisjointMutError::IndexOutOfBounds)
+        );
+    }
+
+    #[test]
+    fn chunks() {
+        let size = |n| NonZeroUsize::new(n).unwrap();
+        let multiple: &NonEmptySlice<i32> = &non_empty_vec![1, 2, 3, 4, 5];
+
+        let chunks = multiple.chunks(size(2));
+        assert_eq!(chunks.non_zero_len().get(), 3);
+        let firsts: Vec<i32> = chunks.map(|chunk| *chunk.first()).collect();
+        assert_eq!(firsts, vec![1, 3, 5]);
+        assert_eq!(multiple.chunks(size(2)).last().as_slice(), &[5]);
+
+        let mut chunks = multiple.chunks(size(10)).into_iter();
+        assert_eq!(
+            chunks.next().map(NonEmptySlice::as_slice),
+            Some(&[1, 2, 3, 4, 5][..])
+        );
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn chunk_by() {
+        let multiple: &NonEmptySlice<(char, i32)> =
+            &non_empty_vec![('a', 1), ('a', 2), ('b', 3), ('c', 4), ('c', 5)];
+
+        let groups: Vec<(char, usize)> = multiple
+            .chunk_by(|a, b| a.0 == b.0)
+            .map(|group| (group.first().0, group.non_zero_len().get()))
+            .collect();
+        assert_eq!(groups, vec![('a', 2), ('b', 1), ('c', 2)]);
+
+        let last = multiple.chunk_by(|a, b| a.1 < b.1).last();
+        assert_eq!(last.len(), 5);
+
+        let largest = multiple
+            .chunk_by(|a, b| a.0 == b.0)
+            .max_by_key(|group| group.len());
+        assert_eq!(largest.as_slice(), &[('c', 4), ('c', 5)]);
+        let back = multiple.chunk_by(|a, b| a.0 == b.0).into_iter().next_back();
+        assert_eq!(back.map(|group| group.first().0), Some('c'));
+    }
+
+    #[test]
+    fn split_non_empty() {
+        let buffer: &NonEmptySlice<u8> = &non_empty_vec![b' ', b'a', b'b', b' ', b' ', b'c', b' '];
+
+        let pieces: Vec<&[u8]> = buffer
+            .split_non_empty(|&b| b == b' ')
+            .map(NonEmptySlice::as_slice)
+            .collect();
+        assert_eq!(pieces, vec![&b"ab"[..], &b"c"[..]]);
+
+        let last = buffer.split_non_empty(|&b| b == b' ').next_back().unwrap();
+        assert_eq!(last.as_slice(), b"c");
+
+        let blanks: &NonEmptySlice<u8> = &non_empty_vec![b' ', b' '];
+        assert!(blanks.split_non_empty(|&b| b == b' ').next().is_none());
+    }
+
+    #[test]
+    fn windows() {
+        let size = |n| NonZeroUsize::new(n).unwrap();
+        let multiple: &NonEmptySlice<i32> = &non_empty_vec![1, 2, 3, 4];
+
+        let windows = multiple.windows(size(2));
+        assert_eq!(windows.len(), 3);
+        let sums: Vec<i32> = windows
+            .map(|window| window.first() + window.last())
+            .collect();
+        assert_eq!(sums, vec![3, 5, 7]);
+
+        let last = multiple.windows(size(4)).next_back().unwrap();
+        assert_eq!(last.as_slice(), &[1, 2, 3, 4]);
+
+        assert_eq!(multiple.windows(size(5)).len(), 0);
+    }
+
+    #[test]
+    fn windows_non_empty() {
+        let size = |n| NonZeroUsize::new(n).unwrap();
+        let multiple: &NonEmptySlice<i32> = &non_empty_vec![1, 2, 3, 4];
+
+        let windows = multiple.windows_non_empty(size(3)).unwrap();
+        assert_eq!(windows.non_zero_len().get(), 2);
+        assert_eq!(windows.map(|window| window.last()).max(), &4);
+        let whole = multiple.windows_non_empty(size(4)).unwrap().first();
+        assert_eq!(whole.as_slice(), &[1, 2, 3, 4]);
+        assert!(multiple.windows_non_empty(size(5)).is_none());
+    }
+
+    #[test]
+    fn split_at() {
+        fn sum(slice: &NonEmptySlice<i32>) -> i32 {
+            match NonZeroUsize::new(slice.len() / 2).map(|mid| slice.split_at_non_empty(mid)) {
+                Some(Ok((left, right))) => sum(left) + sum(right),
+                _ => *slice.first(),
+            }
+        }
+
+        let mid = |n| NonZeroUsize::new(n).unwrap();
+        let multiple: &NonEmptySlice<i32> = &non_empty_vec![10, 20, 30];
+
+        let (left, right) = multiple.split_at(mid(1));
+        assert_eq!((left.as_slice(), right), (&[10][..], &[20, 30][..]));
+
+        let (left, right) = multiple.split_at(mid(3));
+        assert_eq!((left.as_slice(), right), (&[10, 20, 30][..], &[][..]));
+
+        let (left, right) = multiple.split_at_non_empty(mid(2)).unwrap();
+        assert_eq!(
+            (left.as_slice(), right.as_slice()),
+            (&[10, 20][..], &[30][..])
+        );
+
+        assert_eq!(
+            multiple.split_at_non_empty(mid(3)).unwrap_err(),
+            error::SplitError
+        );
+
+        assert_eq!(sum(multiple), 60);
+    }
+
+    #[test]
+    fn split_at_mut() {
+        let mid = |n| NonZeroUsize::new(n).unwrap();
+        let multiple: &mut NonEmptySlice<_> = &mut non_empty_vec![10, 20, 30];
+
+        let (left, right) = multiple.split_at_mut(mid(1));
+        left.first_mut().clone_from(&right[1]);
+        assert_eq!(multiple.as_slice(), &[30, 20, 30]);
+
+        let (left, right) = multiple.split_at_mut(mid(3));
+        assert_eq!(left.len(), 3);
+        assert!(right.is_empty());
+
+        let (left, right) = multiple.split_at_mut_non_empty(mid(2)).unwrap();
+        std::mem::swap(left.last_mut(), right.first_mut());
+        assert_eq!(multiple.as_slice(), &[30, 30, 20]);
+
+        assert_eq!(
+            multiple.split_at_mut_non_empty(mid(3)).unwrap_err(),
+            error::SplitError
+        );
+    }
+
+    #[test]
+    fn chunks_mut() {
+        let multiple: &mut NonEmptySlice<_> = &mut non_empty_vec![1, 2, 3, 4, 5];
+
+        let chunks = multiple.chunks_mut(NonZeroUsize::new(2).unwrap());
+        assert_eq!(chunks.len(), 3);
+        for chunk in chunks {
+            chunk.reverse();
+            *chunk.first_mut() *= 10;
+        }
+
+        assert_eq!(multiple.as_slice(), &[20, 1, 40, 3, 50]);
+    }
+
     #[test]
     fn reverse() {
         let multiple: &mut NonEmptySlice<_> = &mut non_empty_vec![10, 20, 30, 40, 50];
@@ -198,6 +1196,412 @@ mod tests {
         assert_eq!(multiple, reverse);
     }
 
+    #[test]
+    fn non_empty_index() {
+        let mut vec = non_empty_vec![1, 2, 3, 4];
+
+        assert_eq!(vec.non_empty_index(1..=2).unwrap(), &[2, 3][..]);
+        assert_eq!(vec.non_empty_index(3..).unwrap(), &[4][..]);
+        assert!(vec.non_empty_index(2..2).is_none());
+        assert!(vec.non_empty_index(2..9).is_none());
+
+        vec.non_empty_index_mut(..2).unwrap().reverse();
+        assert_eq!(vec, [2, 1, 3, 4]);
+    }
+
+    #[test]
+    fn unchecked_access() {
+        let mut vec = non_empty_vec![1, 2, 3, 4];
+
+        unsafe {
+            *vec.get_unchecked_mut(0) = 10;
+            vec.slice_unchecked_mut(2..).reverse();
+
+            assert_eq!(vec.get_unchecked(0), &10);
+            assert_eq!(vec.slice_unchecked(1..=2), &[2, 4][..]);
+        }
+        assert_eq!(vec, [10, 2, 4, 3]);
+    }
+
+    #[test]
+    fn raw_parts() {
+        let mut vec = non_empty_vec![1, 2, 3];
+        let len = vec.non_zero_len();
+
+        let slice = unsafe { NonEmptySlice::from_raw_parts(vec.as_ptr(), len) };
+        assert_eq!(slice, &[1, 2, 3][..]);
+
+        let slice = unsafe { NonEmptySlice::from_raw_parts_mut(vec.as_mut_ptr(), len) };
+        *slice.last_mut() = 30;
+        assert_eq!(vec, [1, 2, 30]);
+    }
+
+    #[test]
+    fn tail_init_non_empty() {
+        fn sum(slice: &NonEmptySlice<i32>) -> i32 {
+            match slice.tail_non_empty() {
+                Some(tail) => slice.first() + sum(tail),
+                None => *slice.first(),
+            }
+        }
+
+        let vec = non_empty_vec![1, 2, 3];
+
+        assert_eq!(sum(&vec), 6);
+        assert_eq!(vec.init_non_empty().unwrap(), &[1, 2][..]);
+        assert!(non_empty_vec![1].tail_non_empty().is_none());
+        assert!(non_empty_vec![1].init_non_empty().is_none());
+    }
+
+    #[test]
+    fn cycle() {
+        let palette: &NonEmptySlice<char> = &non_empty_vec!['r', 'g', 'b'];
+
+        let colors: String = palette.cycle().take(7).collect();
+        assert_eq!(colors, "rgbrgbr");
+        assert_eq!(palette.cycle().size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn first_last_array() {
+        let frame = non_empty_vec![0x7f, 1, 2];
+        let single = non_empty_vec![9];
+
+        assert_eq!(frame.first_array(), &[0x7f]);
+        assert_eq!(frame.last_array(), &[2]);
+        assert_eq!(frame.split_first_array(), (&[0x7f], &[1, 2][..]));
+        assert_eq!(frame.split_last_array(), (&[0x7f, 1][..], &[2]));
+        assert_eq!(single.split_first_array(), (&[9], &[][..]));
+    }
+
+    #[test]
+    fn first_last_n() {
+        let vec = non_empty_vec![1, 2, 3, 4];
+        let two = NonZeroUsize::new(2).unwrap();
+        let five = NonZeroUsize::new(5).unwrap();
+
+        assert_eq!(vec.first_n(two).unwrap(), &[1, 2][..]);
+        assert_eq!(vec.last_n(two).unwrap(), &[3, 4][..]);
+        assert_eq!(vec.first_n(vec.non_zero_len()).unwrap(), &vec[..]);
+        assert!(vec.first_n(five).is_none());
+        assert!(vec.last_n(five).is_none());
+    }
+
+    #[test]
+    fn from_array() {
+        let slice = NonEmptySlice::from_array(&[1, 2, 3]);
+        assert_eq!(slice, &[1, 2, 3][..]);
+
+        static TABLE: &NonEmptySlice<&str> = crate::non_empty_slice!["low", "high"];
+        assert_eq!(TABLE.first(), &"low");
+
+        let options: &'static NonEmptySlice<i32> = crate::non_empty_slice![1, 2, 3,];
+        assert_eq!(options.non_zero_len().get(), 3);
+    }
+
+    #[test]
+    fn join_display() {
+        let words: &NonEmptySlice<&str> = &non_empty_vec!["a", "b", "c"];
+
+        assert_eq!(words.join_display(", "), "a, b, c");
+        assert_eq!(non_empty_vec![1].join_display(", "), "1");
+    }
+
+    #[test]
+    fn repeat_non_zero() {
+        let multiple: &NonEmptySlice<i32> = &non_empty_vec![10, 20];
+
+        let result = multiple.repeat_non_zero(NonZeroUsize::new(3).unwrap());
+
+        assert_eq!(result, non_empty_vec![10, 20, 10, 20, 10, 20]);
+    }
+
+    #[test]
+    fn partition_map() {
+        let multiple: &NonEmptySlice<i32> = &non_empty_vec![1, 2, 3, 4, 5];
+        let split = |&v: &i32| {
+            if v % 2 == 0 {
+                Either::Left(v)
+            } else {
+                Either::Right(v.to_string())
+            }
+        };
+
+        let (even, odd) = multiple.partition_map(split);
+        assert_eq!(even, vec![2, 4]);
+        assert_eq!(odd, vec!["1", "3", "5"]);
+
+        assert_eq!(
+            multiple.partition_map_non_empty(split),
+            Partitioned::Both(
+                non_empty_vec![2, 4],
+                non_empty_vec!["1".to_string(), "3".to_string(), "5".to_string()]
+            )
+        );
+
+        let evens: &NonEmptySlice<i32> = &non_empty_vec![2, 4];
+        let result = evens.partition_map_non_empty(split);
+        assert_eq!(result, Partitioned::Left(non_empty_vec![2, 4]));
+        assert!(result.right().is_none());
+    }
+
+    #[test]
+    fn boxed() {
+        let boxed: Box<NonEmptySlice<i32>> = non_empty_vec![10, 20, 30].into();
+
+        assert_eq!(boxed.non_zero_len().get(), 3);
+        assert_eq!(boxed.clone().into_first(), 10);
+        assert_eq!(boxed.clone().into_last(), 30);
+        assert_eq!(boxed.into_non_empty_vec(), non_empty_vec![10, 20, 30]);
+
+        let leaked: &'static NonEmptySlice<i32> = NonEmptySlice::boxed_singleton(1).leak();
+        assert_eq!(leaked.first(), &1);
+
+        let singleton = NonEmptySlice::boxed_singleton("a");
+        assert_eq!(singleton.as_slice(), &["a"]);
+        assert_eq!(&*singleton.into_boxed_slice(), &["a"]);
+    }
+
+    #[test]
+    fn min_max() {
+        let multiple: &NonEmptySlice<i32> = &non_empty_vec![30, 10, 50, 20, 40];
+
+        assert_eq!(multiple.max(), &50);
+        assert_eq!(multiple.min(), &10);
+        assert_eq!(multiple.max_by(|a, b| b.cmp(a)), &10);
+        assert_eq!(multiple.min_by(|a, b| b.cmp(a)), &50);
+        assert_eq!(multiple.max_by_key(|v| v % 30), &20);
+        assert_eq!(multiple.min_by_key(|v| v % 30), &30);
+
+        let one: &NonEmptySlice<i32> = &non_empty_vec![7];
+        assert_eq!(one.max(), &7);
+        assert_eq!(one.min(), &7);
+    }
+
+    #[test]
+    fn min_max_ties() {
+        let pairs: &NonEmptySlice<(i32, char)> =
+            &non_empty_vec![(1, 'a'), (2, 'b'), (2, 'c'), (1, 'd')];
+
+        assert_eq!(pairs.max_by_key(|p| p.0), &(2, 'c'));
+        assert_eq!(pairs.min_by_key(|p| p.0), &(1, 'a'));
+        assert_eq!(pairs.max_by(|a, b| a.0.cmp(&b.0)), &(2, 'c'));
+        assert_eq!(pairs.min_by(|a, b| a.0.cmp(&b.0)), &(1, 'a'));
+        assert_eq!(
+            pairs.minmax_by(|a, b| a.0.cmp(&b.0)),
+            (&(1, 'a'), &(2, 'c'))
+        );
+    }
+
+    #[test]
+    fn minmax() {
+        let multiple: &NonEmptySlice<i32> = &non_empty_vec![30, 10, 50, 20, 40];
+
+        assert_eq!(multiple.minmax(), (&10, &50));
+        assert_eq!(non_empty_vec![7].as_non_empty_slice().minmax(), (&7, &7));
+    }
+
+    #[test]
+    fn position_min_max() {
+        let multiple: &NonEmptySlice<i32> = &non_empty_vec![30, 10, 50, 10, 50];
+
+        assert_eq!(multiple.position_max(), 4);
+        assert_eq!(multiple.position_min(), 1);
+        assert_eq!(multiple.position_max_by_key(|&v| -v), 3);
+        assert_eq!(multiple.position_min_by_key(|&v| -v), 2);
+        assert_eq!(multiple.position_max_by(|a, b| b.cmp(a)), 3);
+        assert_eq!(multiple.position_min_by(|a, b| b.cmp(a)), 2);
+
+        let one: &NonEmptySlice<i32> = &non_empty_vec![7];
+        assert_eq!(one.position_max(), 0);
+        assert_eq!(one.position_min(), 0);
+    }
+
+    #[test]
+    fn map_cumulative() {
+        let multiple: &NonEmptySlice<i32> = &non_empty_vec![1, 2, 3, 4];
+
+        assert_eq!(multiple.map(|v| v * 10), non_empty_vec![10, 20, 30, 40]);
+        assert_eq!(
+            multiple.cumulative(|a, b| a + b),
+            non_empty_vec![1, 3, 6, 10]
+        );
+        assert_eq!(
+            multiple.cumulative(|a, b| *a.max(b)),
+            non_empty_vec![1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn fold_first() {
+        let multiple: &NonEmptySlice<i32> = &non_empty_vec![1, 2, 3, 4];
+        assert_eq!(multiple.fold_first(|a, b| a * b), 24);
+
+        let one: &NonEmptySlice<i32> = &non_empty_vec![7];
+        assert_eq!(one.fold_first(|a, b| a - b), 7);
+    }
+
+    #[test]
+    fn binary_search() {
+        let multiple: &NonEmptySlice<i32> = &non_empty_vec![10, 20, 30];
+
+        assert_eq!(multiple.binary_search(&20), Ok(1));
+        assert_eq!(multiple.binary_search(&5), Err(0));
+        assert_eq!(multiple.binary_search(&35), Err(3));
+        assert_eq!(multiple.binary_search_by(|v| v.cmp(&30)), Ok(2));
+        assert_eq!(multiple.binary_search_by_key(&2, |v| v / 10), Ok(1));
+    }
+
+    #[test]
+    fn partition_point() {
+        let multiple: &NonEmptySlice<i32> = &non_empty_vec![1, 2, 3, 10, 20];
+
+        assert_eq!(multiple.partition_point(|&v| v < 5), 3);
+        assert_eq!(multiple.partition_point(|&v| v < 0), 0);
+        assert_eq!(multiple.partition_point(|&v| v < 50), 5);
+
+        let halves = multiple.split_at_partition_point(|&v| v < 5);
+        assert_eq!(
+            halves.left().map(NonEmptySlice::as_slice),
+            Some(&[1, 2, 3][..])
+        );
+        assert_eq!(
+            halves.right().map(NonEmptySlice::as_slice),
+            Some(&[10, 20][..])
+        );
+
+        assert_eq!(
+            multiple.split_at_partition_point(|&v| v < 0),
+            Halves::Right(multiple)
+        );
+        assert_eq!(
+            multiple.split_at_partition_point(|&v| v < 50),
+            Halves::Left(multiple)
+        );
+        assert_eq!(
+            multiple.split_at_partition_point(|&v| v < 50).into_slices(),
+            (&[1, 2, 3, 10, 20][..], &[][..])
+        );
+    }
+
+    #[test]
+    fn as_sorted() {
+        let sorted = non_empty_vec![1, 2, 2, 5];
+        let unsorted = non_empty_vec![2, 1];
+
+        assert_eq!(
+            sorted.as_sorted().map(|s| s.as_slice()),
+            Some(&[1, 2, 2, 5][..])
+        );
+        assert!(unsorted.as_sorted().is_none());
+        assert_eq!(sorted.as_sorted().map(|s| s.last()), Some(&5));
+        // SAFETY: The slice is sorted.
+        let assumed = unsafe { sorted.assume_sorted() };
+        assert!(assumed.contains(&5));
+        assert_eq!(assumed.first(), &1);
+    }
+
+    #[test]
+    fn sort() {
+        let mut vec = non_empty_vec![30, 10, 20];
+        let slice: &mut NonEmptySlice<i32> = vec.as_non_empty_slice_mut();
+
+        slice.sort();
+        assert_eq!(*slice, [10, 20, 30]);
+
+        slice.sort_by(|a, b| b.cmp(a));
+        assert_eq!(*slice, [30, 20, 10]);
+
+        slice.sort_unstable();
+        assert_eq!(*slice, [10, 20, 30]);
+
+        slice.sort_unstable_by(|a, b| b.cmp(a));
+        assert_eq!(*slice, [30, 20, 10]);
+
+        slice.sort_by_key(|v| v % 30);
+        assert_eq!(*slice, [30, 10, 20]);
+
+        slice.sort_unstable_by_key(|v| -v);
+        assert_eq!(*slice, [30, 20, 10]);
+
+        slice.sort_by_cached_key(|v| v.to_string());
+        assert_eq!(*slice, [10, 20, 30]);
+    }
+
+    #[test]
+    fn select_nth_unstable() {
+        let mut vec = non_empty_vec![50, 10, 40, 20, 30];
+        let multiple: &mut NonEmptySlice<i32> = &mut vec;
+
+        let (lower, median, upper) = multiple.select_nth_unstable(2);
+        assert_eq!(*median, 30);
+        assert!(lower.iter().all(|&v| v < 30));
+        assert!(upper.iter().all(|&v| v > 30));
+
+        let (_, max, upper) = multiple.select_nth_unstable_by(0, |a, b| b.cmp(a));
+        assert_eq!(*max, 50);
+        assert_eq!(upper.len(), 4);
+
+        let (lower, last, upper) = multiple.select_nth_unstable_by_key(4, |&v| v % 50);
+        assert_eq!(*last, 40);
+        assert_eq!(lower.len(), 4);
+        assert!(upper.is_empty());
+    }
+
+    #[test]
+    fn run_lengths() {
+        let count = |n| NonZeroUsize::new(n).unwrap();
+        let multiple: &NonEmptySlice<i32> = &non_empty_vec![1, 1, 2, 3, 3, 3];
+
+        assert_eq!(
+            multiple.run_lengths(),
+            non_empty_vec![(&1, count(2)), (&2, count(1)), (&3, count(3))]
+        );
+    }
+
+    #[test]
+    fn prefix_suffix() {
+        let frame: &NonEmptySlice<u8> = &non_empty_vec![0xAA, 0x01, 0x02, 0x55];
+
+        assert!(frame.starts_with(&[0xAA]));
+        assert!(frame.ends_with(&[0x02, 0x55]));
+        assert!(!frame.starts_with(&[0x55]));
+
+        assert_eq!(frame.strip_prefix(&[0xAA]), Some(&[0x01, 0x02, 0x55][..]));
+        assert_eq!(frame.strip_suffix(&[0x55]), Some(&[0xAA, 0x01, 0x02][..]));
+        assert_eq!(frame.strip_prefix(&[0x01]), None);
+
+        let payload = frame
+            .strip_prefix_non_empty(&[0xAA])
+            .and_then(|rest| rest.strip_suffix_non_empty(&[0x55]))
+            .unwrap();
+        assert_eq!(payload.as_slice(), &[0x01, 0x02]);
+
+        assert_eq!(frame.strip_prefix(frame), Some(&[][..]));
+        assert!(frame.strip_prefix_non_empty(frame).is_none());
+        assert!(frame.strip_suffix_non_empty(frame).is_none());
+    }
+
+    #[test]
+    fn concat_join() {
+        let nested = non_empty_vec![
+            non_empty_vec![1, 2],
+            non_empty_vec![3],
+            non_empty_vec![4, 5]
+        ];
+
+        assert_eq!(nested.concat(), non_empty_vec![1, 2, 3, 4, 5]);
+        assert_eq!(nested.join(&0), non_empty_vec![1, 2, 0, 3, 0, 4, 5]);
+
+        let one = non_empty_vec![non_empty_vec![1]];
+        assert_eq!(one.join(&0), non_empty_vec![1]);
+
+        let a: &NonEmptySlice<i32> = &non_empty_vec![1];
+        let b: &NonEmptySlice<i32> = &non_empty_vec![2, 3];
+        let borrowed: &NonEmptySlice<&NonEmptySlice<i32>> = &non_empty_vec![a, b];
+        assert_eq!(borrowed.concat(), non_empty_vec![1, 2, 3]);
+    }
+
     #[test]
     fn non_empty_slice_of_simple_struct() {
         // No clone, no PartialEq, no Eq
@@ -245,4 +1649,24 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn try_from_mut_slice() -> Result<(), error::Empty> {
+        let mut array = [30, 20, 10];
+        let result: &mut NonEmptySlice<i32> = (&mut array[..]).try_into()?;
+        result.reverse();
+        assert_eq!(array, [10, 20, 30]);
+
+        let mut vec = vec![1, 2];
+        let result: &mut NonEmptySlice<i32> = (&mut vec).try_into()?;
+        *result.first_mut() = 3;
+        assert_eq!(vec, vec![3, 2]);
+
+        let mut vec: Vec<i32> = Vec::new();
+        let result: Result<&mut NonEmptySlice<i32>, _> = (&mut vec).try_into();
+        assert!(result.is_err());
+        assert!(NonEmptySlice::try_from_mut_slice(&mut vec[..]).is_err());
+
+        Ok(())
+    }
 }
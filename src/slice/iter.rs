@@ -1,84 +1,317 @@
-use std::{ops::Deref, slice::Iter};
+use std::{
+    ops::{Deref, Range},
+    slice::{ChunkBy, Chunks, ChunksMut, Iter, IterMut, Split, Windows},
+};
 
-use crate::NonEmptyVec;
+use crate::{iter::non_empty_iterator_methods, NonEmptyIterator, NonEmptySlice};
 
-#[derive(Clone)]
 pub struct NonEmptyIter<'a, T>(Iter<'a, T>);
 
+impl<T> Clone for NonEmptyIter<'_, T> {
+    fn clone(&self) -> Self {
+        NonEmptyIter(self.0.clone())
+    }
+}
+
 impl<'a, T> NonEmptyIter<'a, T> {
     pub(crate) fn new_unchecked(iter: Iter<'a, T>) -> Self {
         NonEmptyIter(iter)
     }
 
-    pub fn map<B, F>(self, f: F) -> NonEmptyMap<Self, F>
-    where
-        Self: Sized,
-        F: FnMut(&T) -> B,
-    {
-        NonEmptyMap::new(self, f)
-    }
+    non_empty_iterator_methods!();
 }
 
-impl<'a, T> Iterator for NonEmptyIter<'a, T> {
+impl<'a, T> IntoIterator for NonEmptyIter<'a, T> {
     type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<'a, T> NonEmptyIterator for NonEmptyIter<'a, T> {}
+
+impl<'a, T> Deref for NonEmptyIter<'a, T> {
+    type Target = Iter<'a, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct NonEmptyIterMut<'a, T>(IterMut<'a, T>);
+
+impl<'a, T> NonEmptyIterMut<'a, T> {
+    pub(crate) fn new_unchecked(iter: IterMut<'a, T>) -> Self {
+        NonEmptyIterMut(iter)
+    }
+
+    non_empty_iterator_methods!();
+}
+
+impl<'a, T> IntoIterator for NonEmptyIterMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<'a, T> NonEmptyIterator for NonEmptyIterMut<'a, T> {}
+
+impl<'a, T> Deref for NonEmptyIterMut<'a, T> {
+    type Target = IterMut<'a, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct NonEmptyIndices(Range<usize>);
+
+impl NonEmptyIndices {
+    pub(crate) fn new_unchecked(range: Range<usize>) -> Self {
+        debug_assert!(!range.is_empty());
+        NonEmptyIndices(range)
+    }
+}
+
+impl Iterator for NonEmptyIndices {
+    type Item = usize;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.0.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
 }
 
-impl<'a, T> ExactSizeIterator for NonEmptyIter<'a, T> {
+impl DoubleEndedIterator for NonEmptyIndices {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl ExactSizeIterator for NonEmptyIndices {
     fn len(&self) -> usize {
         self.0.len()
     }
 }
 
-impl<'a, T> Deref for NonEmptyIter<'a, T> {
-    type Target = Iter<'a, T>;
+impl Deref for NonEmptyIndices {
+    type Target = Range<usize>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
-pub struct NonEmptyMap<I, F> {
-    iter: I,
-    f: F,
+#[derive(Clone)]
+pub struct NonEmptySlices<I>(I);
+
+impl<I> NonEmptySlices<I> {
+    /// # Safety
+    ///
+    /// `iter` must never yield an empty slice.
+    pub(crate) unsafe fn new_unchecked(iter: I) -> Self {
+        NonEmptySlices(iter)
+    }
+}
+
+impl<'a, T: 'a, I: Iterator<Item = &'a [T]>> Iterator for NonEmptySlices<I> {
+    type Item = &'a NonEmptySlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: The constructor's caller guarantees no slice is empty.
+        self.0
+            .next()
+            .map(|slice| unsafe { NonEmptySlice::new_unchecked(slice) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, T: 'a, I: DoubleEndedIterator<Item = &'a [T]>> DoubleEndedIterator for NonEmptySlices<I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // SAFETY: See `next`.
+        self.0
+            .next_back()
+            .map(|slice| unsafe { NonEmptySlice::new_unchecked(slice) })
+    }
+}
+
+impl<'a, T: 'a, I: ExactSizeIterator<Item = &'a [T]>> ExactSizeIterator for NonEmptySlices<I> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[derive(Clone)]
+pub struct NonEmptyWindows<'a, T>(NonEmptySlices<Windows<'a, T>>);
+
+impl<'a, T> NonEmptyWindows<'a, T> {
+    pub(crate) fn new(windows: Windows<'a, T>) -> Option<Self> {
+        if windows.len() == 0 {
+            None
+        } else {
+            // SAFETY: `windows` requires a non-zero size and never yields
+            // empty windows.
+            Some(NonEmptyWindows(unsafe {
+                NonEmptySlices::new_unchecked(windows)
+            }))
+        }
+    }
+
+    non_empty_iterator_methods!();
+}
+
+impl<'a, T> IntoIterator for NonEmptyWindows<'a, T> {
+    type Item = &'a NonEmptySlice<T>;
+    type IntoIter = NonEmptySlices<Windows<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<'a, T> NonEmptyIterator for NonEmptyWindows<'a, T> {}
+
+#[derive(Clone)]
+pub struct NonEmptyChunks<'a, T>(NonEmptySlices<Chunks<'a, T>>);
+
+impl<'a, T> NonEmptyChunks<'a, T> {
+    pub(crate) fn new(chunks: Chunks<'a, T>) -> Self {
+        debug_assert!(chunks.len() > 0);
+        // SAFETY: `chunks` never yields empty chunks.
+        NonEmptyChunks(unsafe { NonEmptySlices::new_unchecked(chunks) })
+    }
+
+    non_empty_iterator_methods!();
+}
+
+impl<'a, T> IntoIterator for NonEmptyChunks<'a, T> {
+    type Item = &'a NonEmptySlice<T>;
+    type IntoIter = NonEmptySlices<Chunks<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
 }
 
-impl<B, I: Iterator, F> Iterator for NonEmptyMap<I, F>
+impl<'a, T> NonEmptyIterator for NonEmptyChunks<'a, T> {}
+
+pub struct NonEmptyChunkBy<'a, T, P>(NonEmptySlices<ChunkBy<'a, T, P>>);
+
+impl<'a, T, P> NonEmptyChunkBy<'a, T, P>
 where
-    F: FnMut(I::Item) -> B,
+    P: FnMut(&T, &T) -> bool,
 {
-    type Item = B;
+    pub(crate) fn new(chunks: ChunkBy<'a, T, P>) -> Self {
+        // SAFETY: `chunk_by` never yields empty groups.
+        NonEmptyChunkBy(unsafe { NonEmptySlices::new_unchecked(chunks) })
+    }
 
-    #[inline]
-    fn next(&mut self) -> Option<B> {
-        self.iter.next().map(&mut self.f)
+    non_empty_iterator_methods!();
+}
+
+impl<'a, T, P> IntoIterator for NonEmptyChunkBy<'a, T, P>
+where
+    P: FnMut(&T, &T) -> bool,
+{
+    type Item = &'a NonEmptySlice<T>;
+    type IntoIter = NonEmptySlices<ChunkBy<'a, T, P>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
     }
 }
 
-impl<'a, B, I: ExactSizeIterator, F> ExactSizeIterator for NonEmptyMap<I, F>
+impl<'a, T, P> NonEmptyIterator for NonEmptyChunkBy<'a, T, P> where P: FnMut(&T, &T) -> bool {}
+
+pub struct NonEmptySplit<'a, T, P>(Split<'a, T, P>)
 where
-    F: FnMut(I::Item) -> B,
+    P: FnMut(&T) -> bool;
+
+impl<'a, T, P> NonEmptySplit<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
 {
-    fn len(&self) -> usize {
-        self.iter.len()
+    pub(crate) fn new(split: Split<'a, T, P>) -> Self {
+        NonEmptySplit(split)
     }
 }
 
-impl<I, F> NonEmptyMap<I, F> {
-    fn new(iter: I, f: F) -> NonEmptyMap<I, F> {
-        NonEmptyMap { iter, f }
+impl<'a, T, P> Iterator for NonEmptySplit<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = &'a NonEmptySlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0
+            .by_ref()
+            .find_map(|piece| NonEmptySlice::try_from_slice(piece).ok())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.0.size_hint().1)
     }
 }
 
-impl<'a, A, B, F> NonEmptyMap<NonEmptyIter<'a, A>, F>
+impl<'a, T, P> DoubleEndedIterator for NonEmptySplit<'a, T, P>
 where
-    F: FnMut(&A) -> B,
+    P: FnMut(&T) -> bool,
 {
-    pub fn collect(self) -> NonEmptyVec<B> {
-        NonEmptyVec::try_from(self.iter.0.map(self.f).collect::<Vec<_>>()).unwrap()
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0
+            .by_ref()
+            .rev()
+            .find_map(|piece| NonEmptySlice::try_from_slice(piece).ok())
+    }
+}
+
+pub struct NonEmptyChunksMut<'a, T>(ChunksMut<'a, T>);
+
+impl<'a, T> NonEmptyChunksMut<'a, T> {
+    pub(crate) fn new(chunks: ChunksMut<'a, T>) -> Self {
+        NonEmptyChunksMut(chunks)
+    }
+}
+
+impl<'a, T> Iterator for NonEmptyChunksMut<'a, T> {
+    type Item = &'a mut NonEmptySlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: `chunks_mut` never yields empty chunks.
+        self.0
+            .next()
+            .map(|chunk| unsafe { NonEmptySlice::new_unchecked_mut(chunk) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for NonEmptyChunksMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // SAFETY: `chunks_mut` never yields empty chunks.
+        self.0
+            .next_back()
+            .map(|chunk| unsafe { NonEmptySlice::new_unchecked_mut(chunk) })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for NonEmptyChunksMut<'a, T> {
+    fn len(&self) -> usize {
+        self.0.len()
     }
 }
 
@@ -104,7 +337,7 @@ mod tests {
     fn non_empty_collect() {
         let vec = non_empty_vec![10, 20, 30, 40, 50];
 
-        let result: NonEmptyVec<_> = vec.iter().map(|v| v * 10).collect();
+        let result: NonEmptyVec<_> = vec.iter().map(|v| v * 10).collect_non_empty();
 
         assert_eq!(result, non_empty_vec![100, 200, 300, 400, 500]);
 
@@ -112,4 +345,63 @@ mod tests {
 
         assert_eq!(result, vec![400, 500]);
     }
+
+    #[test]
+    fn into_iter_is_non_empty() {
+        let vec = non_empty_vec![1, 2, 3];
+
+        fn assert_non_empty<I: crate::IntoNonEmptyIterator>(_: &I) {}
+
+        assert_non_empty(&&vec);
+        assert_eq!(
+            non_empty_vec![&vec, &vec]
+                .non_empty_into_iter()
+                .flat_map(|v| v)
+                .collect_non_empty::<NonEmptyVec<_>>()
+                .len(),
+            6
+        );
+
+        let slice: &crate::NonEmptySlice<i32> = &vec;
+        assert_non_empty(&slice);
+        assert_eq!(slice.into_iter().len(), 3);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut vec = non_empty_vec![1, 2, 3];
+
+        for value in vec.iter_mut() {
+            *value *= 10;
+        }
+        assert_eq!(vec, [10, 20, 30]);
+
+        let previous = vec
+            .iter_mut()
+            .map(|v| std::mem::replace(v, 0))
+            .collect_non_empty::<NonEmptyVec<_>>();
+        assert_eq!(previous, non_empty_vec![10, 20, 30]);
+        assert_eq!(vec, [0, 0, 0]);
+
+        for value in &mut vec {
+            *value += 1;
+        }
+        assert_eq!(vec, [1, 1, 1]);
+    }
+
+    #[test]
+    fn indices() {
+        let vec = non_empty_vec![10, 20, 30];
+
+        let indices = vec.indices();
+
+        assert_eq!(indices.len(), 3);
+        assert_eq!(indices.start, 0);
+        assert_eq!(indices.collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(vec.indices().rev().collect::<Vec<_>>(), vec![2, 1, 0]);
+
+        let result: Vec<_> = vec.zip_indices().collect();
+
+        assert_eq!(result, vec![(0, &10), (1, &20), (2, &30)]);
+    }
 }
@@ -0,0 +1,27 @@
+#[cfg(feature = "arc-swap")]
+mod arc;
+mod by_key;
+mod merge;
+mod non_empty;
+mod order;
+mod quantile;
+#[cfg(feature = "rand")]
+mod random;
+mod set;
+mod set_ops;
+mod slice;
+mod vec;
+
+#[cfg(feature = "arc-swap")]
+pub use arc::ArcSortedVec;
+pub use by_key::{SortedByCachedKeyVec, SortedByKeyVec};
+pub use merge::SortedMerge;
+pub use non_empty::NonEmptySortedSlice;
+pub use order::{Ascending, Descending, SortOrder, TotalOrder};
+pub use quantile::Interpolation;
+pub use set::SortedSet;
+pub use set_ops::{SortedDifference, SortedIntersection, SortedSymmetricDifference, SortedUnion};
+pub use slice::SortedSlice;
+pub use vec::SortedVec;
+
+pub type DescendingSortedVec<T> = SortedVec<T, Descending>;
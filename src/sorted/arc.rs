@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
+
+use super::{order::Ascending, SortedVec};
+
+pub struct ArcSortedVec<T, O = Ascending> {
+    inner: Arc<ArcSwap<SortedVec<T, O>>>,
+}
+
+impl<T, O> ArcSortedVec<T, O> {
+    pub fn new(vec: SortedVec<T, O>) -> ArcSortedVec<T, O> {
+        ArcSortedVec {
+            inner: Arc::new(ArcSwap::from_pointee(vec)),
+        }
+    }
+
+    pub fn load(&self) -> Arc<SortedVec<T, O>> {
+        self.inner.load_full()
+    }
+
+    pub fn store(&self, vec: SortedVec<T, O>) {
+        self.inner.store(Arc::new(vec))
+    }
+}
+
+impl<T: Clone, O: Clone> ArcSortedVec<T, O> {
+    pub fn update<F: FnMut(&mut SortedVec<T, O>)>(&self, mut f: F) {
+        self.inner.rcu(|current| {
+            let mut next = SortedVec::clone(current);
+            f(&mut next);
+            next
+        });
+    }
+}
+
+impl<T, O> Clone for ArcSortedVec<T, O> {
+    fn clone(&self) -> Self {
+        ArcSortedVec {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T, O: Default> Default for ArcSortedVec<T, O> {
+    fn default() -> Self {
+        ArcSortedVec::new(SortedVec::default())
+    }
+}
+
+impl<T, O> From<SortedVec<T, O>> for ArcSortedVec<T, O> {
+    fn from(vec: SortedVec<T, O>) -> Self {
+        ArcSortedVec::new(vec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn update_is_shared_between_clones() {
+        let routes = ArcSortedVec::new(SortedVec::sort_vec(vec![30, 10]));
+        let snapshot = routes.load();
+        let reader = routes.clone();
+
+        routes.update(|vec| {
+            vec.insert(20);
+        });
+        thread::spawn(move || assert_eq!(reader.load().as_slice(), &[10, 20, 30]))
+            .join()
+            .unwrap();
+
+        assert_eq!(snapshot.as_slice(), &[10, 30]);
+        routes.store(SortedVec::new());
+        assert!(routes.load().is_empty());
+    }
+}
@@ -0,0 +1,297 @@
+use std::{
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, Range},
+};
+
+/// A vector sorted by `key`: the key of every element compares greater than
+/// or equal to the key of the one before it.
+pub struct SortedByKeyVec<T, K, F>
+where
+    F: Fn(&T) -> K,
+{
+    inner: Vec<T>,
+    key: F,
+    _key: PhantomData<fn(&T) -> K>,
+}
+
+impl<T, K: Ord, F> SortedByKeyVec<T, K, F>
+where
+    F: Fn(&T) -> K,
+{
+    pub fn new(key: F) -> Self {
+        SortedByKeyVec {
+            inner: Vec::new(),
+            key,
+            _key: PhantomData,
+        }
+    }
+
+    pub fn sort_vec(mut vec: Vec<T>, key: F) -> Self {
+        vec.sort_by_key(&key);
+        SortedByKeyVec {
+            inner: vec,
+            key,
+            _key: PhantomData,
+        }
+    }
+
+    pub fn insert(&mut self, value: T) -> usize {
+        let key = (self.key)(&value);
+        let index = self.inner.partition_point(|probe| (self.key)(probe) <= key);
+        self.inner.insert(index, value);
+        index
+    }
+
+    pub fn lower_bound(&self, key: &K) -> usize {
+        self.inner.partition_point(|probe| (self.key)(probe) < *key)
+    }
+
+    pub fn upper_bound(&self, key: &K) -> usize {
+        self.inner
+            .partition_point(|probe| (self.key)(probe) <= *key)
+    }
+
+    pub fn equal_range(&self, key: &K) -> Range<usize> {
+        self.lower_bound(key)..self.upper_bound(key)
+    }
+
+    pub fn key_of(&self, value: &T) -> K {
+        (self.key)(value)
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.inner
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.inner
+    }
+}
+
+impl<T: Clone, K, F> Clone for SortedByKeyVec<T, K, F>
+where
+    F: Fn(&T) -> K + Clone,
+{
+    fn clone(&self) -> Self {
+        SortedByKeyVec {
+            inner: self.inner.clone(),
+            key: self.key.clone(),
+            _key: PhantomData,
+        }
+    }
+}
+
+impl<T: fmt::Debug, K, F> fmt::Debug for SortedByKeyVec<T, K, F>
+where
+    F: Fn(&T) -> K,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.inner, f)
+    }
+}
+
+impl<T, K, F> Deref for SortedByKeyVec<T, K, F>
+where
+    F: Fn(&T) -> K,
+{
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T, K, F> IntoIterator for SortedByKeyVec<T, K, F>
+where
+    F: Fn(&T) -> K,
+{
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+/// A [`SortedByKeyVec`] that also stores the key of every element, so `key`
+/// runs once per element instead of on every search.
+pub struct SortedByCachedKeyVec<T, K, F>
+where
+    F: Fn(&T) -> K,
+{
+    keys: Vec<K>,
+    inner: Vec<T>,
+    key: F,
+}
+
+impl<T, K: Ord, F> SortedByCachedKeyVec<T, K, F>
+where
+    F: Fn(&T) -> K,
+{
+    pub fn new(key: F) -> Self {
+        SortedByCachedKeyVec {
+            keys: Vec::new(),
+            inner: Vec::new(),
+            key,
+        }
+    }
+
+    pub fn sort_vec(vec: Vec<T>, key: F) -> Self {
+        let mut decorated: Vec<(K, T)> =
+            vec.into_iter().map(|value| (key(&value), value)).collect();
+        decorated.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let (keys, inner) = decorated.into_iter().unzip();
+        SortedByCachedKeyVec { keys, inner, key }
+    }
+
+    pub fn insert(&mut self, value: T) -> usize {
+        let key = (self.key)(&value);
+        let index = self.keys.partition_point(|probe| *probe <= key);
+        self.keys.insert(index, key);
+        self.inner.insert(index, value);
+        index
+    }
+
+    pub fn lower_bound(&self, key: &K) -> usize {
+        self.keys.partition_point(|probe| probe < key)
+    }
+
+    pub fn upper_bound(&self, key: &K) -> usize {
+        self.keys.partition_point(|probe| probe <= key)
+    }
+
+    pub fn equal_range(&self, key: &K) -> Range<usize> {
+        self.lower_bound(key)..self.upper_bound(key)
+    }
+
+    pub fn key_of(&self, value: &T) -> K {
+        (self.key)(value)
+    }
+
+    pub fn keys(&self) -> &[K] {
+        &self.keys
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.inner
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.inner
+    }
+}
+
+impl<T: Clone, K: Clone, F> Clone for SortedByCachedKeyVec<T, K, F>
+where
+    F: Fn(&T) -> K + Clone,
+{
+    fn clone(&self) -> Self {
+        SortedByCachedKeyVec {
+            keys: self.keys.clone(),
+            inner: self.inner.clone(),
+            key: self.key.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug, K, F> fmt::Debug for SortedByCachedKeyVec<T, K, F>
+where
+    F: Fn(&T) -> K,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.inner, f)
+    }
+}
+
+impl<T, K, F> Deref for SortedByCachedKeyVec<T, K, F>
+where
+    F: Fn(&T) -> K,
+{
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T, K, F> IntoIterator for SortedByCachedKeyVec<T, K, F>
+where
+    F: Fn(&T) -> K,
+{
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn sort_vec_by_key() {
+        let words = vec!["ccc", "a", "bb"];
+        let sorted = SortedByKeyVec::sort_vec(words, |word: &&str| word.len());
+
+        assert_eq!(sorted.as_slice(), &["a", "bb", "ccc"]);
+        assert_eq!(sorted.key_of(&"dddd"), 4);
+    }
+
+    #[test]
+    fn insert_by_key() {
+        let mut sorted = SortedByKeyVec::new(|pair: &(i32, char)| pair.0);
+
+        sorted.insert((2, 'a'));
+        sorted.insert((1, 'b'));
+        assert_eq!(sorted.insert((2, 'c')), 2);
+
+        assert_eq!(sorted.into_vec(), vec![(1, 'b'), (2, 'a'), (2, 'c')]);
+    }
+
+    #[test]
+    fn bounds_by_key() {
+        let pairs = vec![(2, 'a'), (1, 'b'), (2, 'c'), (3, 'd')];
+        let sorted = SortedByKeyVec::sort_vec(pairs, |pair: &(i32, char)| pair.0);
+
+        assert_eq!(sorted.lower_bound(&2), 1);
+        assert_eq!(sorted.upper_bound(&2), 3);
+        assert_eq!(sorted.equal_range(&2), 1..3);
+        assert_eq!(sorted.equal_range(&4), 4..4);
+    }
+
+    #[test]
+    fn sort_vec_by_cached_key() {
+        let words = vec!["banana", "Cherry", "apple"];
+        let sorted = SortedByCachedKeyVec::sort_vec(words, |word: &&str| word.to_lowercase());
+
+        assert_eq!(sorted.as_slice(), &["apple", "banana", "Cherry"]);
+        assert_eq!(sorted.keys(), &["apple", "banana", "cherry"]);
+        assert_eq!(sorted.lower_bound(&"b".to_owned()), 1);
+    }
+
+    #[test]
+    fn cached_key_calls() {
+        let calls = Cell::new(0);
+        let key = |pair: &(i32, char)| {
+            calls.set(calls.get() + 1);
+            pair.0
+        };
+        let mut sorted = SortedByCachedKeyVec::sort_vec(vec![(3, 'a'), (1, 'b'), (2, 'c')], key);
+        assert_eq!(calls.get(), 3);
+
+        assert_eq!(sorted.insert((2, 'd')), 2);
+        assert_eq!(sorted.equal_range(&2), 1..3);
+        assert_eq!(sorted.upper_bound(&3), 4);
+        assert_eq!(calls.get(), 4);
+
+        assert_eq!(
+            sorted.into_vec(),
+            vec![(1, 'b'), (2, 'c'), (2, 'd'), (3, 'a')]
+        );
+    }
+}
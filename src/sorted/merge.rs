@@ -0,0 +1,110 @@
+use std::{
+    cmp::Ordering, collections::BinaryHeap, iter::FusedIterator, marker::PhantomData, slice,
+};
+
+use super::{
+    order::{Ascending, SortOrder},
+    SortedSlice,
+};
+
+struct Head<'a, T, O> {
+    item: &'a T,
+    source: usize,
+    order: PhantomData<fn() -> O>,
+}
+
+impl<T, O: SortOrder<T>> Ord for Head<'_, T, O> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap, so the comparison is reversed to pop
+        // the smallest item first, breaking ties by source.
+        O::compare(other.item, self.item).then_with(|| other.source.cmp(&self.source))
+    }
+}
+
+impl<T, O: SortOrder<T>> PartialOrd for Head<'_, T, O> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, O: SortOrder<T>> PartialEq for Head<'_, T, O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T, O: SortOrder<T>> Eq for Head<'_, T, O> {}
+
+pub struct SortedMerge<'a, T, O = Ascending> {
+    heap: BinaryHeap<Head<'a, T, O>>,
+    sources: Vec<slice::Iter<'a, T>>,
+}
+
+impl<'a, T, O: SortOrder<T>> SortedMerge<'a, T, O> {
+    pub fn new<I>(slices: I) -> SortedMerge<'a, T, O>
+    where
+        O: 'a,
+        I: IntoIterator<Item = &'a SortedSlice<T, O>>,
+    {
+        let mut sources: Vec<_> = slices.into_iter().map(|slice| slice.iter()).collect();
+        let heap = sources
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(source, iter)| {
+                Some(Head {
+                    item: iter.next()?,
+                    source,
+                    order: PhantomData,
+                })
+            })
+            .collect();
+        SortedMerge { heap, sources }
+    }
+}
+
+impl<'a, T, O: SortOrder<T>> Iterator for SortedMerge<'a, T, O> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let head = self.heap.pop()?;
+        if let Some(item) = self.sources.get_mut(head.source).and_then(Iterator::next) {
+            self.heap.push(Head { item, ..head });
+        }
+        Some(head.item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, O: SortOrder<T>> ExactSizeIterator for SortedMerge<'_, T, O> {
+    fn len(&self) -> usize {
+        self.heap.len()
+            + self
+                .sources
+                .iter()
+                .map(ExactSizeIterator::len)
+                .sum::<usize>()
+    }
+}
+
+impl<T, O: SortOrder<T>> FusedIterator for SortedMerge<'_, T, O> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SortedVec;
+
+    #[test]
+    fn merge_iter() {
+        let a = SortedVec::sort_vec(vec![1, 4, 7]);
+        let b = SortedVec::sort_vec(vec![2, 4]);
+        let c = SortedVec::new();
+        let merged = SortedMerge::new([&*a, &*b, &*c]);
+
+        assert_eq!(merged.len(), 5);
+        assert_eq!(merged.copied().collect::<Vec<_>>(), vec![1, 2, 4, 4, 7]);
+    }
+}
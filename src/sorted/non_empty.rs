@@ -0,0 +1,77 @@
+use std::{fmt, ops::Deref};
+
+use super::{order::Ascending, SortedSlice};
+use crate::NonEmptySlice;
+
+/// A [`SortedSlice`] that holds at least one element.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptySortedSlice<T, O = Ascending> {
+    inner: SortedSlice<T, O>,
+}
+
+impl<T, O> SortedSlice<T, O> {
+    pub fn as_non_empty(&self) -> Option<&NonEmptySortedSlice<T, O>> {
+        if self.is_empty() {
+            None
+        } else {
+            // SAFETY: We just checked the slice is non-empty.
+            Some(unsafe { NonEmptySortedSlice::from_sorted_unchecked(self) })
+        }
+    }
+}
+
+impl<T, O> NonEmptySortedSlice<T, O> {
+    pub(crate) unsafe fn from_sorted_unchecked(slice: &SortedSlice<T, O>) -> &Self {
+        // SAFETY: This type is `repr(transparent)`, so we can safely cast the
+        // references like this. The caller guarantees the slice is non-empty.
+        unsafe { &*(slice as *const SortedSlice<T, O> as *const NonEmptySortedSlice<T, O>) }
+    }
+
+    pub fn as_sorted_slice(&self) -> &SortedSlice<T, O> {
+        &self.inner
+    }
+
+    pub fn as_non_empty_slice(&self) -> &NonEmptySlice<T> {
+        // SAFETY: A `NonEmptySortedSlice` always holds at least one element.
+        unsafe { NonEmptySlice::new_unchecked(self.inner.as_slice()) }
+    }
+
+    pub fn first(&self) -> &T {
+        self.as_non_empty_slice().first()
+    }
+
+    pub fn last(&self) -> &T {
+        self.as_non_empty_slice().last()
+    }
+}
+
+impl<T: fmt::Debug, O> fmt::Debug for NonEmptySortedSlice<T, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<T, O> Deref for NonEmptySortedSlice<T, O> {
+    type Target = SortedSlice<T, O>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Descending, SortedVec};
+
+    #[test]
+    fn as_non_empty() {
+        let vec = SortedVec::sort_vec_with(vec![2, 9, 4], Descending);
+        let non_empty = vec.as_non_empty().unwrap();
+
+        assert_eq!(non_empty.first(), &9);
+        assert_eq!(non_empty.last(), &2);
+        assert_eq!(non_empty.as_non_empty_slice().len(), 3);
+        assert!(SortedVec::<i32>::new().as_non_empty().is_none());
+    }
+}
@@ -0,0 +1,75 @@
+use std::cmp::Ordering;
+
+pub trait SortOrder<T: ?Sized> {
+    fn compare(a: &T, b: &T) -> Ordering;
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ascending;
+
+impl<T: Ord + ?Sized> SortOrder<T> for Ascending {
+    fn compare(a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Descending;
+
+impl<T: Ord + ?Sized> SortOrder<T> for Descending {
+    fn compare(a: &T, b: &T) -> Ordering {
+        b.cmp(a)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TotalOrder;
+
+impl SortOrder<f32> for TotalOrder {
+    fn compare(a: &f32, b: &f32) -> Ordering {
+        a.total_cmp(b)
+    }
+}
+
+impl SortOrder<f64> for TotalOrder {
+    fn compare(a: &f64, b: &f64) -> Ordering {
+        a.total_cmp(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::*;
+    use crate::SortedVec;
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct ByLen;
+
+    impl SortOrder<&str> for ByLen {
+        fn compare(a: &&str, b: &&str) -> Ordering {
+            a.len().cmp(&b.len())
+        }
+    }
+
+    #[test]
+    fn custom_order() {
+        let mut words = SortedVec::sort_vec_with(vec!["ccc", "a", "bb"], ByLen);
+        words.insert("dd");
+
+        assert_eq!(words.as_slice(), &["a", "bb", "dd", "ccc"]);
+        assert_eq!(words.lower_bound(&"xx"), 1);
+        assert!(words.contains(&"zzz"));
+    }
+
+    #[test]
+    fn sort_floats() {
+        let mut floats = SortedVec::sort_floats(vec![2.5, f64::NAN, -0.0, 1.0, 0.0]);
+        floats.insert(-1.5);
+
+        assert_eq!(&floats[..5], &[-1.5, -0.0, 0.0, 1.0, 2.5]);
+        assert!(floats.last().is_some_and(|x| x.is_nan()));
+        assert_eq!(floats.lower_bound(&0.5), 3);
+    }
+}
@@ -0,0 +1,248 @@
+use super::{
+    order::{Ascending, Descending, TotalOrder},
+    NonEmptySortedSlice, SortedSlice,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    #[default]
+    Linear,
+    Lower,
+    Higher,
+    Nearest,
+    Midpoint,
+}
+
+struct Position {
+    last: usize,
+    at: f64,
+    descending: bool,
+}
+
+impl Position {
+    fn new(len: usize, descending: bool, q: f64) -> Option<Position> {
+        if !(0.0..=1.0).contains(&q) {
+            return None;
+        }
+        let last = len.checked_sub(1)?;
+        Some(Position {
+            last,
+            at: q * last as f64,
+            descending,
+        })
+    }
+
+    fn index(&self, rank: f64) -> usize {
+        let rank = rank as usize;
+        if self.descending {
+            self.last - rank
+        } else {
+            rank
+        }
+    }
+
+    fn lower(&self) -> usize {
+        self.index(self.at.floor())
+    }
+
+    fn higher(&self) -> usize {
+        self.index(self.at.ceil())
+    }
+
+    fn nearest(&self) -> usize {
+        self.index(self.at.round_ties_even())
+    }
+}
+
+fn quantile<T: Copy + Into<f64>>(
+    slice: &[T],
+    descending: bool,
+    q: f64,
+    interpolation: Interpolation,
+) -> Option<f64> {
+    let position = Position::new(slice.len(), descending, q)?;
+    let at = |index: usize| slice.get(index).copied().map(Into::into);
+    let (lower, higher) = (at(position.lower())?, at(position.higher())?);
+    Some(match interpolation {
+        Interpolation::Linear => lower + (higher - lower) * position.at.fract(),
+        Interpolation::Lower => lower,
+        Interpolation::Higher => higher,
+        Interpolation::Nearest => at(position.nearest())?,
+        Interpolation::Midpoint => (lower + higher) / 2.0,
+    })
+}
+
+fn quantile_index(len: usize, descending: bool, q: f64, higher: bool) -> Option<usize> {
+    let position = Position::new(len, descending, q)?;
+    Some(if higher {
+        position.higher()
+    } else {
+        position.lower()
+    })
+}
+
+macro_rules! impl_quantiles {
+    ($order:ty, $descending:literal) => {
+        impl<T> SortedSlice<T, $order> {
+            pub fn median_low(&self) -> Option<&T> {
+                self.quantile_low(0.5)
+            }
+
+            pub fn median_high(&self) -> Option<&T> {
+                self.quantile_high(0.5)
+            }
+
+            pub fn quantile_low(&self, q: f64) -> Option<&T> {
+                self.get(quantile_index(self.len(), $descending, q, false)?)
+            }
+
+            pub fn quantile_high(&self, q: f64) -> Option<&T> {
+                self.get(quantile_index(self.len(), $descending, q, true)?)
+            }
+        }
+
+        impl<T: Copy + Into<f64>> SortedSlice<T, $order> {
+            pub fn median(&self) -> Option<f64> {
+                self.quantile(0.5, Interpolation::Linear)
+            }
+
+            pub fn percentile(&self, p: f64, interpolation: Interpolation) -> Option<f64> {
+                self.quantile(p / 100.0, interpolation)
+            }
+
+            pub fn quantile(&self, q: f64, interpolation: Interpolation) -> Option<f64> {
+                quantile(self, $descending, q, interpolation)
+            }
+        }
+
+        impl<T> NonEmptySortedSlice<T, $order> {
+            pub fn median_low(&self) -> &T {
+                self.quantile_low(0.5)
+            }
+
+            pub fn median_high(&self) -> &T {
+                self.quantile_high(0.5)
+            }
+
+            pub fn quantile_low(&self, q: f64) -> &T {
+                assert!((0.0..=1.0).contains(&q), "quantile {q} is outside [0, 1]");
+                self.as_sorted_slice()
+                    .quantile_low(q)
+                    .expect("a non-empty slice has every quantile")
+            }
+
+            pub fn quantile_high(&self, q: f64) -> &T {
+                assert!((0.0..=1.0).contains(&q), "quantile {q} is outside [0, 1]");
+                self.as_sorted_slice()
+                    .quantile_high(q)
+                    .expect("a non-empty slice has every quantile")
+            }
+        }
+
+        impl<T: Copy + Into<f64>> NonEmptySortedSlice<T, $order> {
+            pub fn median(&self) -> f64 {
+                self.quantile(0.5, Interpolation::Linear)
+            }
+
+            pub fn percentile(&self, p: f64, interpolation: Interpolation) -> f64 {
+                self.quantile(p / 100.0, interpolation)
+            }
+
+            pub fn quantile(&self, q: f64, interpolation: Interpolation) -> f64 {
+                assert!((0.0..=1.0).contains(&q), "quantile {q} is outside [0, 1]");
+                quantile(self, $descending, q, interpolation)
+                    .expect("a non-empty slice has every quantile")
+            }
+        }
+    };
+}
+
+impl_quantiles!(Ascending, false);
+impl_quantiles!(TotalOrder, false);
+impl_quantiles!(Descending, true);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{non_empty_vec, SortedVec};
+
+    #[test]
+    fn median() {
+        assert_eq!(SortedVec::sort_vec(vec![3, 1, 2]).median(), Some(2.0));
+        assert_eq!(SortedVec::sort_vec(vec![4, 1, 3, 2]).median(), Some(2.5));
+        assert_eq!(SortedVec::<i32>::new().median(), None);
+    }
+
+    #[test]
+    fn quantile_interpolation() {
+        let vec = SortedVec::sort_vec(vec![10, 20, 30, 40]);
+
+        assert_eq!(vec.quantile(0.0, Interpolation::Linear), Some(10.0));
+        assert_eq!(vec.quantile(1.0, Interpolation::Linear), Some(40.0));
+        assert_eq!(vec.quantile(0.5, Interpolation::Lower), Some(20.0));
+        assert_eq!(vec.quantile(0.5, Interpolation::Higher), Some(30.0));
+        assert_eq!(vec.quantile(0.5, Interpolation::Midpoint), Some(25.0));
+        assert_eq!(vec.quantile(0.5, Interpolation::Nearest), Some(30.0));
+        assert_eq!(vec.percentile(25.0, Interpolation::Linear), Some(17.5));
+        assert_eq!(vec.quantile(1.5, Interpolation::Linear), None);
+        assert_eq!(vec.quantile(f64::NAN, Interpolation::Linear), None);
+    }
+
+    #[test]
+    fn descending_quantiles() {
+        let vec = SortedVec::sort_vec_with(vec![10, 20, 30, 40, 50], Descending);
+
+        assert_eq!(vec.quantile(0.1, Interpolation::Lower), Some(10.0));
+        assert_eq!(vec.percentile(90.0, Interpolation::Lower), Some(40.0));
+        assert_eq!(vec.percentile(25.0, Interpolation::Linear), Some(20.0));
+        assert_eq!(vec.median(), Some(30.0));
+    }
+
+    #[test]
+    fn float_quantiles() {
+        let vec = SortedVec::sort_floats(vec![0.5, 2.5, 1.5]);
+
+        assert_eq!(vec.median(), Some(1.5));
+        assert_eq!(vec.quantile(0.25, Interpolation::Linear), Some(1.0));
+    }
+
+    #[test]
+    fn non_empty_quantiles() {
+        let mut vec = non_empty_vec![7, 1, 5, 3];
+        let sorted = vec.sort_and_view();
+
+        assert_eq!(sorted.median(), 4.0);
+        assert_eq!(sorted.quantile(1.0, Interpolation::Lower), 7.0);
+        assert_eq!(sorted.percentile(50.0, Interpolation::Higher), 5.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside [0, 1]")]
+    fn non_empty_quantile_out_of_range() {
+        let mut vec = non_empty_vec![1];
+
+        vec.sort_and_view().quantile(2.0, Interpolation::Linear);
+    }
+
+    #[test]
+    fn order_statistics_of_any_type() {
+        let names = SortedVec::sort_vec(vec!["carol", "alice", "dave", "bob"]);
+
+        assert_eq!(names.median_low(), Some(&"bob"));
+        assert_eq!(names.median_high(), Some(&"carol"));
+        assert_eq!(names.quantile_low(1.0), Some(&"dave"));
+        assert_eq!(names.quantile_high(0.0), Some(&"alice"));
+        assert_eq!(names.quantile_low(1.5), None);
+        assert_eq!(SortedVec::<String>::new().median_low(), None);
+
+        let descending = SortedVec::sort_vec_with(vec!["a", "b", "c"], Descending);
+        assert_eq!(descending.quantile_low(0.0), Some(&"a"));
+        assert_eq!(descending.quantile_high(0.75), Some(&"c"));
+
+        let mut words = non_empty_vec!["pear", "fig", "apple"];
+        let sorted = words.sort_and_view();
+        assert_eq!(sorted.median_low(), &"fig");
+        assert_eq!(sorted.median_high(), &"fig");
+        assert_eq!(sorted.quantile_high(1.0), &"pear");
+    }
+}
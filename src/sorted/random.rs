@@ -0,0 +1,104 @@
+use std::{collections::BTreeSet, num::NonZeroUsize, ops::RangeInclusive};
+
+use rand::{
+    distr::{uniform::SampleUniform, Uniform},
+    seq::index,
+    Rng,
+};
+
+use super::SortedVec;
+
+impl<T: SampleUniform + Ord> SortedVec<T> {
+    pub fn random_increasing<R: Rng + ?Sized>(
+        rng: &mut R,
+        len: NonZeroUsize,
+        range: RangeInclusive<T>,
+    ) -> Option<SortedVec<T>> {
+        let (start, end) = range.into_inner();
+        let uniform = Uniform::new_inclusive(start, end).ok()?;
+        let values = (0..len.get()).map(|_| rng.sample(&uniform)).collect();
+        Some(SortedVec::sort_vec(values))
+    }
+}
+
+macro_rules! random_strictly_increasing {
+    ($($int:ty),+) => {$(
+        impl SortedVec<$int> {
+            pub fn random_strictly_increasing<R: Rng + ?Sized>(
+                rng: &mut R,
+                len: NonZeroUsize,
+                range: RangeInclusive<$int>,
+            ) -> Option<SortedVec<$int>> {
+                let (start, end) = range.into_inner();
+                let span = end as i128 - start as i128 + 1;
+                if span < len.get() as i128 {
+                    return None;
+                }
+                let values = match usize::try_from(span) {
+                    Ok(span) => index::sample(rng, span, len.get())
+                        .into_iter()
+                        .map(|offset| (start as i128 + offset as i128) as $int)
+                        .collect(),
+                    Err(_) => {
+                        let mut values = BTreeSet::new();
+                        while values.len() < len.get() {
+                            values.insert(rng.random_range(start..=end));
+                        }
+                        values.into_iter().collect()
+                    }
+                };
+                Some(SortedVec::sort_vec(values))
+            }
+        }
+    )+};
+}
+
+random_strictly_increasing!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+#[cfg(test)]
+mod tests {
+
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    fn len(n: usize) -> NonZeroUsize {
+        NonZeroUsize::new(n).unwrap()
+    }
+
+    #[test]
+    fn increasing() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let vec = SortedVec::random_increasing(&mut rng, len(100), 10..=20).unwrap();
+        assert_eq!(vec.len(), 100);
+        assert!(vec.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(vec.iter().all(|value| (10..=20).contains(value)));
+
+        let vec = SortedVec::random_increasing(&mut rng, len(3), 5..=5).unwrap();
+        assert_eq!(vec.as_slice(), &[5, 5, 5]);
+
+        let empty = SortedVec::random_increasing(&mut rng, len(1), RangeInclusive::new(5, 4));
+        assert!(empty.is_none());
+    }
+
+    #[test]
+    fn strictly_increasing() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let vec =
+            SortedVec::<i32>::random_strictly_increasing(&mut rng, len(50), -30..=30).unwrap();
+        assert_eq!(vec.len(), 50);
+        assert!(vec.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(vec.iter().all(|value| (-30..=30).contains(value)));
+
+        let vec = SortedVec::<u8>::random_strictly_increasing(&mut rng, len(256), 0..=255).unwrap();
+        assert!(vec.iter().copied().eq(0..=255));
+
+        let vec =
+            SortedVec::<u64>::random_strictly_increasing(&mut rng, len(10), 0..=u64::MAX).unwrap();
+        assert!(vec.windows(2).all(|pair| pair[0] < pair[1]));
+
+        assert!(SortedVec::<u8>::random_strictly_increasing(&mut rng, len(11), 0..=9).is_none());
+    }
+}
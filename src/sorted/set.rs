@@ -0,0 +1,210 @@
+use std::{cmp::Ordering, fmt, marker::PhantomData, mem, ops::Deref};
+
+use super::{
+    order::{Ascending, SortOrder},
+    SortedSlice, SortedVec,
+};
+
+/// A sorted vector without duplicates: every element compares strictly
+/// greater than the one before it according to `O`.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortedSet<T, O = Ascending> {
+    inner: Vec<T>,
+    order: PhantomData<fn() -> O>,
+}
+
+impl<T> SortedSet<T> {
+    pub const fn new() -> SortedSet<T> {
+        SortedSet {
+            inner: Vec::new(),
+            order: PhantomData,
+        }
+    }
+}
+
+impl<T: Ord> SortedSet<T> {
+    pub fn sort_vec(vec: Vec<T>) -> SortedSet<T> {
+        SortedSet::sort_vec_with(vec, Ascending)
+    }
+}
+
+impl<T, O> SortedSet<T, O> {
+    pub fn new_with(_order: O) -> SortedSet<T, O> {
+        SortedSet {
+            inner: Vec::new(),
+            order: PhantomData,
+        }
+    }
+
+    pub fn as_sorted_slice(&self) -> &SortedSlice<T, O> {
+        // SAFETY: A strictly increasing vector is also sorted.
+        unsafe { SortedSlice::from_slice_unchecked(&self.inner) }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.inner
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.inner
+    }
+
+    pub fn into_sorted_vec(self) -> SortedVec<T, O> {
+        // SAFETY: A strictly increasing vector is also sorted.
+        unsafe { SortedVec::assume_sorted_unchecked(self.inner) }
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+}
+
+impl<T, O: SortOrder<T>> SortedSet<T, O> {
+    pub fn sort_vec_with(mut vec: Vec<T>, _order: O) -> SortedSet<T, O> {
+        vec.sort_by(O::compare);
+        SortedSet::dedup_sorted(vec)
+    }
+
+    fn dedup_sorted(mut inner: Vec<T>) -> SortedSet<T, O> {
+        inner.dedup_by(|a, b| O::compare(a, b) == Ordering::Equal);
+        SortedSet {
+            inner,
+            order: PhantomData,
+        }
+    }
+
+    pub fn insert(&mut self, value: T) -> bool {
+        match self.binary_search(&value) {
+            Ok(_) => false,
+            Err(index) => {
+                self.inner.insert(index, value);
+                true
+            }
+        }
+    }
+
+    pub fn insert_batch(&mut self, mut items: Vec<T>) {
+        items.sort_by(O::compare);
+        // SAFETY: A strictly increasing vector is also sorted, and the
+        // batch was just sorted.
+        let (current, batch) = unsafe {
+            (
+                SortedVec::<T, O>::assume_sorted_unchecked(mem::take(&mut self.inner)),
+                SortedVec::assume_sorted_unchecked(items),
+            )
+        };
+        *self = SortedSet::dedup_sorted(current.merge(batch).into_vec());
+    }
+}
+
+impl<T, O: Default> Default for SortedSet<T, O> {
+    fn default() -> Self {
+        SortedSet::new_with(O::default())
+    }
+}
+
+impl<T, O: SortOrder<T> + Default> FromIterator<T> for SortedSet<T, O> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        SortedSet::sort_vec_with(iter.into_iter().collect(), O::default())
+    }
+}
+
+impl<T, O: SortOrder<T>> Extend<T> for SortedSet<T, O> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.insert_batch(iter.into_iter().collect());
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for SortedSet<T> {
+    fn from(vec: Vec<T>) -> Self {
+        SortedSet::sort_vec(vec)
+    }
+}
+
+impl<T, O: SortOrder<T>> From<SortedVec<T, O>> for SortedSet<T, O> {
+    fn from(vec: SortedVec<T, O>) -> Self {
+        SortedSet::dedup_sorted(vec.into_vec())
+    }
+}
+
+impl<T: fmt::Debug, O> fmt::Debug for SortedSet<T, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.inner.iter()).finish()
+    }
+}
+
+impl<T, O> Deref for SortedSet<T, O> {
+    type Target = SortedSlice<T, O>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_sorted_slice()
+    }
+}
+
+impl<T, O> IntoIterator for SortedSet<T, O> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, T, O> IntoIterator for &'a SortedSet<T, O> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Descending;
+
+    #[test]
+    fn sort_vec_dedups() {
+        let set = SortedSet::sort_vec(vec![3, 1, 3, 2, 1]);
+
+        assert_eq!(set.as_slice(), &[1, 2, 3]);
+        assert_eq!(format!("{set:?}"), "{1, 2, 3}");
+    }
+
+    #[test]
+    fn insert() {
+        let mut set = SortedSet::new();
+
+        assert!(set.insert(2));
+        assert!(set.insert(1));
+        assert!(!set.insert(2));
+        assert_eq!(set.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn insert_batch() {
+        let mut set = SortedSet::sort_vec(vec![5, 1, 3]);
+
+        set.insert_batch(vec![4, 3, 2, 4]);
+        assert_eq!(set.as_slice(), &[1, 2, 3, 4, 5]);
+
+        let mut descending = SortedSet::sort_vec_with(vec![1, 3], Descending);
+        descending.extend([2, 3, 4]);
+        assert_eq!(descending.as_slice(), &[4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn conversions() {
+        let multiset = SortedVec::sort_vec(vec![2, 1, 2]);
+        let set = SortedSet::from(multiset.clone());
+
+        assert_eq!(multiset.as_slice(), &[1, 2, 2]);
+        assert_eq!(set.as_slice(), &[1, 2]);
+        assert_eq!(set.into_sorted_vec().as_slice(), &[1, 2]);
+
+        let mut collected: SortedSet<i32> = [3, 3, 1].into_iter().collect();
+        collected.extend([1, 2]);
+        assert_eq!(collected.as_slice(), &[1, 2, 3]);
+    }
+}
@@ -0,0 +1,215 @@
+use std::{
+    cmp::Ordering,
+    iter::{FusedIterator, Peekable},
+    marker::PhantomData,
+    ops::{BitAnd, BitOr, BitXor, Sub},
+    slice,
+};
+
+use super::{
+    order::{Ascending, SortOrder},
+    SortedSlice, SortedVec,
+};
+
+type Side<'a, T> = Peekable<slice::Iter<'a, T>>;
+
+macro_rules! set_op {
+    ($name:ident) => {
+        pub struct $name<'a, T, O = Ascending> {
+            left: Side<'a, T>,
+            right: Side<'a, T>,
+            order: PhantomData<fn() -> O>,
+        }
+
+        impl<'a, T, O> $name<'a, T, O> {
+            pub(crate) fn new(left: &'a SortedSlice<T, O>, right: &'a SortedSlice<T, O>) -> Self {
+                $name {
+                    left: left.iter().peekable(),
+                    right: right.iter().peekable(),
+                    order: PhantomData,
+                }
+            }
+        }
+
+        impl<T, O: SortOrder<T>> FusedIterator for $name<'_, T, O> {}
+    };
+}
+
+set_op!(SortedUnion);
+set_op!(SortedIntersection);
+set_op!(SortedDifference);
+set_op!(SortedSymmetricDifference);
+
+impl<'a, T, O: SortOrder<T>> Iterator for SortedUnion<'a, T, O> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.left.peek().copied(), self.right.peek().copied()) {
+            (Some(l), Some(r)) => match O::compare(l, r) {
+                Ordering::Less => self.left.next(),
+                Ordering::Greater => self.right.next(),
+                Ordering::Equal => {
+                    self.right.next();
+                    self.left.next()
+                }
+            },
+            (Some(_), None) => self.left.next(),
+            (None, _) => self.right.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (l, r) = (self.left.len(), self.right.len());
+        (l.max(r), l.checked_add(r))
+    }
+}
+
+impl<'a, T, O: SortOrder<T>> Iterator for SortedIntersection<'a, T, O> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let l = *self.left.peek()?;
+            let r = *self.right.peek()?;
+            match O::compare(l, r) {
+                Ordering::Less => {
+                    self.left.next();
+                }
+                Ordering::Greater => {
+                    self.right.next();
+                }
+                Ordering::Equal => {
+                    self.right.next();
+                    return self.left.next();
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.left.len().min(self.right.len())))
+    }
+}
+
+impl<'a, T, O: SortOrder<T>> Iterator for SortedDifference<'a, T, O> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let l = *self.left.peek()?;
+            let Some(&r) = self.right.peek() else {
+                return self.left.next();
+            };
+            match O::compare(l, r) {
+                Ordering::Less => return self.left.next(),
+                Ordering::Greater => {
+                    self.right.next();
+                }
+                Ordering::Equal => {
+                    self.left.next();
+                    self.right.next();
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (l, r) = (self.left.len(), self.right.len());
+        (l.saturating_sub(r), Some(l))
+    }
+}
+
+impl<'a, T, O: SortOrder<T>> Iterator for SortedSymmetricDifference<'a, T, O> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match (self.left.peek().copied(), self.right.peek().copied()) {
+                (Some(l), Some(r)) => match O::compare(l, r) {
+                    Ordering::Less => return self.left.next(),
+                    Ordering::Greater => return self.right.next(),
+                    Ordering::Equal => {
+                        self.left.next();
+                        self.right.next();
+                    }
+                },
+                (Some(_), None) => return self.left.next(),
+                (None, _) => return self.right.next(),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.left.len().checked_add(self.right.len()))
+    }
+}
+
+macro_rules! impl_set_op {
+    ($trait:ident, $method:ident, $eager:ident) => {
+        impl<T: Clone, O: SortOrder<T>> $trait<&SortedSlice<T, O>> for &SortedSlice<T, O> {
+            type Output = SortedVec<T, O>;
+
+            fn $method(self, rhs: &SortedSlice<T, O>) -> SortedVec<T, O> {
+                self.$eager(rhs)
+            }
+        }
+
+        impl<T: Clone, O: SortOrder<T>> $trait<&SortedVec<T, O>> for &SortedVec<T, O> {
+            type Output = SortedVec<T, O>;
+
+            fn $method(self, rhs: &SortedVec<T, O>) -> SortedVec<T, O> {
+                self.$eager(rhs)
+            }
+        }
+    };
+}
+
+impl_set_op!(BitOr, bitor, union_vec);
+impl_set_op!(BitAnd, bitand, intersection_vec);
+impl_set_op!(Sub, sub, difference_vec);
+impl_set_op!(BitXor, bitxor, symmetric_difference_vec);
+
+#[cfg(test)]
+mod tests {
+    use crate::SortedVec;
+
+    #[test]
+    fn set_operations() {
+        let a = SortedVec::sort_vec(vec![1, 2, 2, 4, 6]);
+        let b = SortedVec::sort_vec(vec![2, 3, 4, 4]);
+
+        assert_eq!(
+            a.union(&b).copied().collect::<Vec<_>>(),
+            vec![1, 2, 2, 3, 4, 4, 6]
+        );
+        assert_eq!(a.intersection(&b).copied().collect::<Vec<_>>(), vec![2, 4]);
+        assert_eq!(a.difference(&b).copied().collect::<Vec<_>>(), vec![1, 2, 6]);
+        assert_eq!(
+            a.symmetric_difference(&b).copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 6]
+        );
+    }
+
+    #[test]
+    fn eager_set_operations() {
+        let a = SortedVec::sort_vec(vec![1, 3, 5]);
+        let b = SortedVec::sort_vec(vec![3, 4]);
+
+        assert_eq!(a.union_vec(&b).as_slice(), &[1, 3, 4, 5]);
+        assert_eq!(a.intersection_vec(&b).as_slice(), &[3]);
+        assert_eq!(a.difference_vec(&b).as_slice(), &[1, 5]);
+        assert_eq!(a.symmetric_difference_vec(&b).as_slice(), &[1, 4, 5]);
+    }
+
+    #[test]
+    fn operators() {
+        let a = SortedVec::sort_vec(vec![1, 2, 3]);
+        let b = SortedVec::sort_vec(vec![2, 3, 4]);
+
+        assert_eq!((&a | &b).as_slice(), &[1, 2, 3, 4]);
+        assert_eq!((&a & &b).as_slice(), &[2, 3]);
+        assert_eq!((&a - &b).as_slice(), &[1]);
+        assert_eq!((&a ^ &b).as_slice(), &[1, 4]);
+        assert_eq!((a.as_sorted_slice() | b.as_sorted_slice()), &a | &b);
+    }
+}
@@ -0,0 +1,489 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    marker::PhantomData,
+    ops::{Bound, Deref, Range, RangeBounds},
+};
+
+use super::{
+    order::{Ascending, SortOrder},
+    set_ops::{SortedDifference, SortedIntersection, SortedSymmetricDifference, SortedUnion},
+    SortedVec,
+};
+
+pub(crate) use error::NotSorted;
+
+mod error {
+    use std::{error::Error, fmt};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct NotSorted {
+        pub(in crate::sorted) index: usize,
+    }
+
+    impl NotSorted {
+        pub fn index(&self) -> usize {
+            self.index
+        }
+    }
+
+    impl fmt::Display for NotSorted {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "element at index {} is out of order", self.index)
+        }
+    }
+
+    impl Error for NotSorted {}
+}
+
+pub(crate) fn check_sorted<T, O: SortOrder<T>>(slice: &[T]) -> Result<(), NotSorted> {
+    match slice
+        .windows(2)
+        .position(|pair| matches!(pair, [a, b] if O::compare(a, b) == Ordering::Greater))
+    {
+        Some(index) => Err(NotSorted { index: index + 1 }),
+        None => Ok(()),
+    }
+}
+
+/// A sorted slice that may hold duplicates: every element compares greater
+/// than or equal to the one before it according to `O`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SortedSlice<T, O = Ascending> {
+    order: PhantomData<fn() -> O>,
+    inner: [T],
+}
+
+impl<T> SortedSlice<T> {
+    /// # Safety
+    ///
+    /// `slice` must be sorted in ascending order according to `T`'s `Ord`
+    /// implementation, as for [`SortedVec::assume_sorted`].
+    pub const unsafe fn new_unchecked(slice: &[T]) -> &SortedSlice<T> {
+        // SAFETY: The caller upholds the contract.
+        unsafe { SortedSlice::from_slice_unchecked(slice) }
+    }
+}
+
+impl<'a> SortedSlice<&'a str> {
+    pub const fn from_sorted_strs<'b>(slice: &'b [&'a str]) -> &'b SortedSlice<&'a str> {
+        let mut remaining = slice;
+        while let [first, tail @ ..] = remaining {
+            if let [second, ..] = tail {
+                assert!(
+                    bytes_le(first.as_bytes(), second.as_bytes()),
+                    "static_sorted! elements are out of order"
+                );
+            }
+            remaining = tail;
+        }
+        // SAFETY: The loop above checked every adjacent pair, and `str`
+        // orders byte-wise.
+        unsafe { SortedSlice::new_unchecked(slice) }
+    }
+}
+
+impl<'a> SortedSlice<&'a [u8]> {
+    pub const fn from_sorted_bytes<'b>(slice: &'b [&'a [u8]]) -> &'b SortedSlice<&'a [u8]> {
+        let mut remaining = slice;
+        while let [first, tail @ ..] = remaining {
+            if let [second, ..] = tail {
+                assert!(
+                    bytes_le(first, second),
+                    "static_sorted! elements are out of order"
+                );
+            }
+            remaining = tail;
+        }
+        // SAFETY: The loop above checked every adjacent pair.
+        unsafe { SortedSlice::new_unchecked(slice) }
+    }
+}
+
+const fn bytes_le(mut left: &[u8], mut right: &[u8]) -> bool {
+    loop {
+        match (left.split_first(), right.split_first()) {
+            (Some((a, left_tail)), Some((b, right_tail))) => {
+                if *a != *b {
+                    return *a < *b;
+                }
+                left = left_tail;
+                right = right_tail;
+            }
+            (None, _) => return true,
+            (Some(_), None) => return false,
+        }
+    }
+}
+
+impl<T: Ord> SortedSlice<T> {
+    pub fn try_from_slice(slice: &[T]) -> Result<&SortedSlice<T>, NotSorted> {
+        SortedSlice::try_from_slice_with(slice, Ascending)
+    }
+}
+
+impl<T, O> SortedSlice<T, O> {
+    pub(crate) const unsafe fn from_slice_unchecked(slice: &[T]) -> &SortedSlice<T, O> {
+        // SAFETY: This type is `repr(transparent)`, so we can safely
+        // cast the references like this.
+        unsafe { &*(slice as *const [T] as *const SortedSlice<T, O>) }
+    }
+
+    /// # Safety
+    ///
+    /// `slice` must be sorted according to `O`, as for
+    /// [`SortedSlice::new_unchecked`].
+    pub unsafe fn new_unchecked_with(slice: &[T], _order: O) -> &SortedSlice<T, O> {
+        // SAFETY: The caller upholds the contract.
+        unsafe { SortedSlice::from_slice_unchecked(slice) }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.inner
+    }
+
+    pub fn binary_search_by_key<K, F: FnMut(&T) -> K>(
+        &self,
+        key: &K,
+        mut f: F,
+    ) -> Result<usize, usize>
+    where
+        O: SortOrder<K>,
+    {
+        self.inner
+            .binary_search_by(|probe| O::compare(&f(probe), key))
+    }
+}
+
+impl<T, O: SortOrder<T>> SortedSlice<T, O> {
+    pub fn try_from_slice_with(slice: &[T], _order: O) -> Result<&SortedSlice<T, O>, NotSorted> {
+        check_sorted::<T, O>(slice)?;
+        // SAFETY: We just checked that the slice is sorted.
+        Ok(unsafe { SortedSlice::from_slice_unchecked(slice) })
+    }
+
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize> {
+        self.inner.binary_search_by(|probe| O::compare(probe, x))
+    }
+
+    pub fn contains(&self, x: &T) -> bool {
+        self.binary_search(x).is_ok()
+    }
+
+    pub fn lower_bound(&self, x: &T) -> usize {
+        self.inner
+            .partition_point(|probe| O::compare(probe, x) == Ordering::Less)
+    }
+
+    pub fn upper_bound(&self, x: &T) -> usize {
+        self.inner
+            .partition_point(|probe| O::compare(probe, x) != Ordering::Greater)
+    }
+
+    pub fn equal_range(&self, x: &T) -> Range<usize> {
+        self.lower_bound(x)..self.upper_bound(x)
+    }
+
+    pub fn rank(&self, x: &T) -> usize {
+        self.lower_bound(x)
+    }
+
+    pub fn position_of(&self, x: &T) -> Option<usize> {
+        let index = self.lower_bound(x);
+        self.inner
+            .get(index)
+            .filter(|candidate| O::compare(candidate, x) == Ordering::Equal)
+            .map(|_| index)
+    }
+
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> &SortedSlice<T, O> {
+        let start = match range.start_bound() {
+            Bound::Included(x) => self.lower_bound(x),
+            Bound::Excluded(x) => self.upper_bound(x),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(x) => self.upper_bound(x),
+            Bound::Excluded(x) => self.lower_bound(x),
+            Bound::Unbounded => self.inner.len(),
+        };
+        let slice = self.inner.get(start..end).unwrap_or(&[]);
+        // SAFETY: A contiguous subslice of a sorted slice is sorted.
+        unsafe { SortedSlice::from_slice_unchecked(slice) }
+    }
+
+    pub fn union<'a>(&'a self, other: &'a SortedSlice<T, O>) -> SortedUnion<'a, T, O> {
+        SortedUnion::new(self, other)
+    }
+
+    pub fn intersection<'a>(
+        &'a self,
+        other: &'a SortedSlice<T, O>,
+    ) -> SortedIntersection<'a, T, O> {
+        SortedIntersection::new(self, other)
+    }
+
+    pub fn difference<'a>(&'a self, other: &'a SortedSlice<T, O>) -> SortedDifference<'a, T, O> {
+        SortedDifference::new(self, other)
+    }
+
+    pub fn symmetric_difference<'a>(
+        &'a self,
+        other: &'a SortedSlice<T, O>,
+    ) -> SortedSymmetricDifference<'a, T, O> {
+        SortedSymmetricDifference::new(self, other)
+    }
+
+    pub fn contains_all(&self, required: &SortedSlice<T, O>) -> bool {
+        required.difference(self).next().is_none()
+    }
+}
+
+impl<T: Clone, O> SortedSlice<T, O> {
+    pub fn to_sorted_vec(&self) -> SortedVec<T, O> {
+        // SAFETY: The elements are copied in their existing order.
+        unsafe { SortedVec::assume_sorted_unchecked(self.inner.to_vec()) }
+    }
+}
+
+impl<T: Clone, O: SortOrder<T>> SortedSlice<T, O> {
+    pub fn missing_from(&self, required: &SortedSlice<T, O>) -> SortedVec<T, O> {
+        required.difference_vec(self)
+    }
+
+    pub fn union_vec(&self, other: &SortedSlice<T, O>) -> SortedVec<T, O> {
+        // SAFETY: The union of two sorted slices is yielded in order.
+        unsafe { SortedVec::assume_sorted_unchecked(self.union(other).cloned().collect()) }
+    }
+
+    pub fn intersection_vec(&self, other: &SortedSlice<T, O>) -> SortedVec<T, O> {
+        // SAFETY: The intersection of two sorted slices is yielded in order.
+        unsafe { SortedVec::assume_sorted_unchecked(self.intersection(other).cloned().collect()) }
+    }
+
+    pub fn difference_vec(&self, other: &SortedSlice<T, O>) -> SortedVec<T, O> {
+        // SAFETY: The difference of two sorted slices is yielded in order.
+        unsafe { SortedVec::assume_sorted_unchecked(self.difference(other).cloned().collect()) }
+    }
+
+    pub fn symmetric_difference_vec(&self, other: &SortedSlice<T, O>) -> SortedVec<T, O> {
+        // SAFETY: The symmetric difference of two sorted slices is yielded
+        // in order.
+        unsafe {
+            SortedVec::assume_sorted_unchecked(self.symmetric_difference(other).cloned().collect())
+        }
+    }
+}
+
+impl<T: fmt::Debug, O> fmt::Debug for SortedSlice<T, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<T, O> Deref for SortedSlice<T, O> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T, O> AsRef<[T]> for SortedSlice<T, O> {
+    fn as_ref(&self) -> &[T] {
+        &self.inner
+    }
+}
+
+impl<'a, T, O> IntoIterator for &'a SortedSlice<T, O> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+// The plain and `sort;` forms compare with `<=`, which is only const for
+// primitives; `str;` and `bytes;` compare byte-wise like their `Ord` impls.
+#[macro_export]
+macro_rules! static_sorted {
+    (str; $($x:expr),+ $(,)?) => {
+        const { $crate::SortedSlice::from_sorted_strs(&[$($x),+]) }
+    };
+    (bytes; $($x:expr),+ $(,)?) => {
+        const { $crate::SortedSlice::from_sorted_bytes(&[$($x as &[u8]),+]) }
+    };
+    (sort $kind:ident; $($x:expr),+ $(,)?) => {
+        compile_error!("static_sorted! can only sort primitive elements; list `str` and `bytes` elements in order")
+    };
+    (sort; $($x:expr),+ $(,)?) => {
+        const {
+            let sorted = &const {
+                let mut array = [$($x),+];
+                let mut i = 1;
+                while i < array.len() {
+                    let mut j = i;
+                    while j > 0 && array[j - 1] > array[j] {
+                        let swapped = array[j];
+                        array[j] = array[j - 1];
+                        array[j - 1] = swapped;
+                        j -= 1;
+                    }
+                    i += 1;
+                }
+                array
+            };
+            // SAFETY: The array was just sorted.
+            unsafe { $crate::SortedSlice::new_unchecked(sorted) }
+        }
+    };
+    ($($x:expr),+ $(,)?) => {
+        const {
+            let array = &[$($x),+];
+            let mut i = 1;
+            while i < array.len() {
+                assert!(array[i - 1] <= array[i], "static_sorted! elements are out of order");
+                i += 1;
+            }
+            // SAFETY: The loop above checked every adjacent pair.
+            unsafe { $crate::SortedSlice::new_unchecked(array) }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Descending;
+
+    #[test]
+    fn deref_and_clone() {
+        let vec = SortedVec::sort_vec(vec![3, 1, 2]);
+        let slice: &SortedSlice<i32> = &vec;
+
+        assert_eq!(slice.as_slice(), &[1, 2, 3]);
+        assert_eq!(slice.len(), 3);
+        assert_eq!(slice.to_sorted_vec(), vec);
+        assert_eq!(format!("{slice:?}"), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn try_from_slice() {
+        assert!(SortedSlice::try_from_slice(&[1, 1, 2]).is_ok());
+        assert!(SortedSlice::<i32>::try_from_slice(&[]).is_ok());
+
+        let err = SortedSlice::try_from_slice(&[1, 3, 2, 0]).unwrap_err();
+        assert_eq!(err.index(), 2);
+        assert_eq!(err.to_string(), "element at index 2 is out of order");
+    }
+
+    #[test]
+    fn assume_sorted() {
+        // SAFETY: The slice is sorted.
+        let slice = unsafe { SortedSlice::new_unchecked(&[1, 2, 3]) };
+        // SAFETY: The vec is sorted.
+        let vec = unsafe { SortedVec::assume_sorted(vec![1, 2, 3]) };
+
+        assert_eq!(slice.to_sorted_vec(), vec);
+    }
+
+    #[test]
+    fn search() {
+        let vec = SortedVec::sort_vec(vec![(3, 'c'), (1, 'a'), (5, 'e')]);
+
+        assert_eq!(vec.binary_search(&(3, 'c')), Ok(1));
+        assert_eq!(vec.binary_search(&(4, 'd')), Err(2));
+        assert_eq!(vec.binary_search_by_key(&5, |&(n, _)| n), Ok(2));
+
+        let descending = SortedVec::sort_vec_with(vec![10, 30, 20, 50, 40], Descending);
+        assert_eq!(descending.binary_search_by_key(&20, |&x| x), Ok(3));
+        assert_eq!(descending.binary_search_by_key(&40, |&x| x), Ok(1));
+        assert_eq!(descending.binary_search_by_key(&35, |&x| x), Err(2));
+        assert!(vec.contains(&(1, 'a')));
+        assert!(!vec.contains(&(1, 'b')));
+    }
+
+    #[test]
+    fn range() {
+        let vec = SortedVec::sort_vec(vec![1, 2, 2, 3, 5, 8]);
+
+        assert_eq!(vec.range(2..5).as_slice(), &[2, 2, 3]);
+        assert_eq!(vec.range(2..=5).as_slice(), &[2, 2, 3, 5]);
+        assert_eq!(vec.range(..3).as_slice(), &[1, 2, 2]);
+        assert_eq!(vec.range(4..).as_slice(), &[5, 8]);
+        assert_eq!(
+            vec.range((Bound::Excluded(2), Bound::Unbounded)).as_slice(),
+            &[3, 5, 8]
+        );
+        assert!(vec.range(6..7).is_empty());
+    }
+
+    #[test]
+    fn bounds() {
+        let vec = SortedVec::sort_vec(vec![1, 2, 2, 2, 4]);
+
+        assert_eq!(vec.lower_bound(&2), 1);
+        assert_eq!(vec.upper_bound(&2), 4);
+        assert_eq!(vec.equal_range(&2), 1..4);
+        assert_eq!(vec.equal_range(&3), 4..4);
+        assert_eq!(vec.equal_range(&0), 0..0);
+    }
+
+    #[test]
+    fn rank_and_position() {
+        let vec = SortedVec::sort_vec(vec![10, 20, 20, 30]);
+
+        assert_eq!(vec.rank(&5), 0);
+        assert_eq!(vec.rank(&20), 1);
+        assert_eq!(vec.rank(&25), 3);
+        assert_eq!(vec.rank(&40), 4);
+        assert_eq!(vec.position_of(&20), Some(1));
+        assert_eq!(vec.position_of(&30), Some(3));
+        assert_eq!(vec.position_of(&25), None);
+    }
+
+    #[test]
+    fn contains_all_and_missing_from() {
+        let available = SortedVec::sort_vec(vec!["fs", "net", "time"]);
+        let covered = SortedVec::sort_vec(vec!["net", "fs"]);
+        let required = SortedVec::sort_vec(vec!["gpu", "net", "audio"]);
+
+        assert!(available.contains_all(&covered));
+        assert!(!available.contains_all(&required));
+        assert!(available.contains_all(&SortedVec::new()));
+        assert_eq!(available.missing_from(&covered).as_slice(), &[] as &[&str]);
+        assert_eq!(
+            available.missing_from(&required).as_slice(),
+            &["audio", "gpu"]
+        );
+    }
+
+    #[test]
+    fn static_sorted() {
+        static KEYWORDS: &SortedSlice<char> = static_sorted!['a', 'f', 'l', 'm'];
+        let sorted: &'static SortedSlice<i32> = static_sorted![sort; 3, -1, 2, 2];
+
+        assert!(KEYWORDS.contains(&'l'));
+        assert_eq!(sorted.as_slice(), &[-1, 2, 2, 3]);
+    }
+
+    #[test]
+    fn static_sorted_strings() {
+        static WORDS: &SortedSlice<&str> = static_sorted![str; "as", "async", "b", "fn", "ä"];
+        static TAGS: &SortedSlice<&[u8]> = static_sorted![bytes; b"", b"GET", b"PUT", b"get"];
+
+        assert!(WORDS.contains(&"async"));
+        assert!(!WORDS.contains(&"a"));
+        assert_eq!(TAGS.binary_search(&&b"PUT"[..]), Ok(2));
+        assert!(SortedSlice::try_from_slice(WORDS.as_slice()).is_ok());
+        assert!(SortedSlice::try_from_slice(TAGS.as_slice()).is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of order")]
+    fn from_sorted_strs_rejects_unsorted() {
+        SortedSlice::from_sorted_strs(&["b", "a"]);
+    }
+}
@@ -0,0 +1,454 @@
+use std::{cmp::Ordering, fmt, marker::PhantomData, mem, ops::Deref};
+
+use super::{
+    merge::SortedMerge,
+    order::{Ascending, Descending, SortOrder, TotalOrder},
+    slice::{check_sorted, NotSorted},
+    SortedSlice,
+};
+
+/// A sorted vector that keeps duplicates: every element compares greater
+/// than or equal to the one before it according to `O`.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortedVec<T, O = Ascending> {
+    inner: Vec<T>,
+    order: PhantomData<fn() -> O>,
+}
+
+impl<T> SortedVec<T> {
+    pub const fn new() -> SortedVec<T> {
+        SortedVec {
+            inner: Vec::new(),
+            order: PhantomData,
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `vec` must be sorted in ascending order according to `T`'s `Ord`
+    /// implementation. Methods of this type rely on that order, and a
+    /// violation may make searches and inserts give wrong results.
+    pub unsafe fn assume_sorted(vec: Vec<T>) -> SortedVec<T> {
+        // SAFETY: The caller upholds the contract.
+        unsafe { SortedVec::assume_sorted_with(vec, Ascending) }
+    }
+
+    /// # Safety
+    ///
+    /// `iter` must yield its items in ascending order, as for
+    /// [`SortedVec::assume_sorted`].
+    pub unsafe fn from_iter_sorted_unchecked<I: IntoIterator<Item = T>>(iter: I) -> SortedVec<T> {
+        // SAFETY: The caller upholds the contract.
+        unsafe { SortedVec::assume_sorted(iter.into_iter().collect()) }
+    }
+}
+
+impl<T> SortedVec<T> {
+    pub fn into_descending(mut self) -> SortedVec<T, Descending> {
+        self.inner.reverse();
+        // SAFETY: An ascending vec read backwards is descending.
+        unsafe { SortedVec::assume_sorted_unchecked(self.inner) }
+    }
+}
+
+impl<T> SortedVec<T, Descending> {
+    pub fn into_ascending(mut self) -> SortedVec<T> {
+        self.inner.reverse();
+        // SAFETY: A descending vec read backwards is ascending.
+        unsafe { SortedVec::assume_sorted_unchecked(self.inner) }
+    }
+}
+
+impl<T> SortedVec<T, TotalOrder>
+where
+    TotalOrder: SortOrder<T>,
+{
+    pub fn sort_floats(vec: Vec<T>) -> SortedVec<T, TotalOrder> {
+        SortedVec::sort_vec_with(vec, TotalOrder)
+    }
+}
+
+impl<T: Ord> SortedVec<T> {
+    pub fn sort_vec(vec: Vec<T>) -> SortedVec<T> {
+        SortedVec::sort_vec_with(vec, Ascending)
+    }
+
+    pub fn try_from_sorted(vec: Vec<T>) -> Result<SortedVec<T>, NotSorted> {
+        SortedVec::try_from_sorted_with(vec, Ascending)
+    }
+
+    pub fn from_iter_verify<I: IntoIterator<Item = T>>(iter: I) -> Result<SortedVec<T>, NotSorted> {
+        SortedVec::from_iter_verify_with(iter, Ascending)
+    }
+}
+
+impl<T, O> SortedVec<T, O> {
+    pub fn new_with(_order: O) -> SortedVec<T, O> {
+        SortedVec {
+            inner: Vec::new(),
+            order: PhantomData,
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `vec` must be sorted according to `O`, as for
+    /// [`SortedVec::assume_sorted`].
+    pub unsafe fn assume_sorted_with(vec: Vec<T>, _order: O) -> SortedVec<T, O> {
+        // SAFETY: The caller upholds the contract.
+        unsafe { SortedVec::assume_sorted_unchecked(vec) }
+    }
+
+    pub(crate) unsafe fn assume_sorted_unchecked(vec: Vec<T>) -> SortedVec<T, O> {
+        SortedVec {
+            inner: vec,
+            order: PhantomData,
+        }
+    }
+
+    pub fn as_sorted_slice(&self) -> &SortedSlice<T, O> {
+        // SAFETY: `inner` is kept sorted by every method of this type.
+        unsafe { SortedSlice::from_slice_unchecked(&self.inner) }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.inner
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.inner
+    }
+
+    pub fn remove_at(&mut self, index: usize) -> T {
+        self.inner.remove(index)
+    }
+
+    /// # Performance
+    ///
+    /// Removing the first element shifts every remaining element down,
+    /// so this is O(n). Popping a whole queue from the front is faster
+    /// through `into_iter`, or with `pop_last` on the reversed order.
+    pub fn pop_first(&mut self) -> Option<T> {
+        if self.inner.is_empty() {
+            None
+        } else {
+            Some(self.inner.remove(0))
+        }
+    }
+
+    pub fn pop_last(&mut self) -> Option<T> {
+        self.inner.pop()
+    }
+
+    #[deprecated(note = "use `pop_first`; the first element is the minimum only under `Ascending`")]
+    pub fn pop_min(&mut self) -> Option<T> {
+        self.pop_first()
+    }
+
+    #[deprecated(note = "use `pop_last`; the last element is the maximum only under `Ascending`")]
+    pub fn pop_max(&mut self) -> Option<T> {
+        self.pop_last()
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+}
+
+impl<T, O: SortOrder<T>> SortedVec<T, O> {
+    pub fn sort_vec_with(mut vec: Vec<T>, order: O) -> SortedVec<T, O> {
+        vec.sort_by(O::compare);
+        // SAFETY: We just sorted the vec.
+        unsafe { SortedVec::assume_sorted_with(vec, order) }
+    }
+
+    pub fn try_from_sorted_with(vec: Vec<T>, order: O) -> Result<SortedVec<T, O>, NotSorted> {
+        check_sorted::<T, O>(&vec)?;
+        // SAFETY: We just checked that the vec is sorted.
+        Ok(unsafe { SortedVec::assume_sorted_with(vec, order) })
+    }
+
+    pub fn from_iter_verify_with<I: IntoIterator<Item = T>>(
+        iter: I,
+        order: O,
+    ) -> Result<SortedVec<T, O>, NotSorted> {
+        let iter = iter.into_iter();
+        let mut vec: Vec<T> = Vec::with_capacity(iter.size_hint().0);
+        for item in iter {
+            if let Some(last) = vec.last() {
+                if O::compare(last, &item) == Ordering::Greater {
+                    return Err(NotSorted { index: vec.len() });
+                }
+            }
+            vec.push(item);
+        }
+        // SAFETY: Every item was checked against the one before it.
+        Ok(unsafe { SortedVec::assume_sorted_with(vec, order) })
+    }
+
+    pub fn insert(&mut self, value: T) -> usize {
+        let index = self.upper_bound(&value);
+        self.inner.insert(index, value);
+        index
+    }
+
+    pub fn merge(self, other: SortedVec<T, O>) -> SortedVec<T, O> {
+        let mut inner = Vec::with_capacity(self.inner.len() + other.inner.len());
+        let mut left = self.inner.into_iter().peekable();
+        let mut right = other.inner.into_iter().peekable();
+        loop {
+            let take_left = match (left.peek(), right.peek()) {
+                (Some(l), Some(r)) => O::compare(l, r) != Ordering::Greater,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+            inner.extend(if take_left { left.next() } else { right.next() });
+        }
+        // SAFETY: Merging two sorted vecs yields their elements in order.
+        unsafe { SortedVec::assume_sorted_unchecked(inner) }
+    }
+
+    pub fn insert_batch(&mut self, mut items: Vec<T>) {
+        items.sort_by(O::compare);
+        // SAFETY: `self.inner` is sorted and the batch was just sorted.
+        let (current, batch) = unsafe {
+            (
+                SortedVec::assume_sorted_unchecked(mem::take(&mut self.inner)),
+                SortedVec::assume_sorted_unchecked(items),
+            )
+        };
+        *self = current.merge(batch);
+    }
+
+    pub fn merge_dedup(self, other: SortedVec<T, O>) -> SortedVec<T, O> {
+        let mut merged = self.merge(other);
+        merged
+            .inner
+            .dedup_by(|a, b| O::compare(a, b) == Ordering::Equal);
+        merged
+    }
+
+    pub fn remove(&mut self, value: &T) -> Option<T> {
+        let index = self.binary_search(value).ok()?;
+        Some(self.inner.remove(index))
+    }
+}
+
+impl<T: Clone, O: SortOrder<T>> SortedVec<T, O> {
+    pub fn merge_k<'a, I>(slices: I) -> SortedVec<T, O>
+    where
+        T: 'a,
+        O: 'a,
+        I: IntoIterator<Item = &'a SortedSlice<T, O>>,
+    {
+        // SAFETY: `SortedMerge` yields the elements of all slices in order.
+        unsafe { SortedVec::assume_sorted_unchecked(SortedMerge::new(slices).cloned().collect()) }
+    }
+}
+
+impl<T, O: Default> Default for SortedVec<T, O> {
+    fn default() -> Self {
+        SortedVec::new_with(O::default())
+    }
+}
+
+impl<T, O: SortOrder<T> + Default> FromIterator<T> for SortedVec<T, O> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        SortedVec::sort_vec_with(iter.into_iter().collect(), O::default())
+    }
+}
+
+impl<T, O: SortOrder<T>> Extend<T> for SortedVec<T, O> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.insert_batch(iter.into_iter().collect());
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for SortedVec<T> {
+    fn from(vec: Vec<T>) -> Self {
+        SortedVec::sort_vec(vec)
+    }
+}
+
+impl<T, O> From<SortedVec<T, O>> for Vec<T> {
+    fn from(vec: SortedVec<T, O>) -> Self {
+        vec.into_vec()
+    }
+}
+
+impl<T: fmt::Debug, O> fmt::Debug for SortedVec<T, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.inner, f)
+    }
+}
+
+impl<T, O> Deref for SortedVec<T, O> {
+    type Target = SortedSlice<T, O>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_sorted_slice()
+    }
+}
+
+impl<T, O> IntoIterator for SortedVec<T, O> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, T, O> IntoIterator for &'a SortedVec<T, O> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Bound;
+
+    use super::*;
+
+    #[test]
+    fn sort_vec() {
+        let sorted = SortedVec::sort_vec(vec![3, 1, 2, 1]);
+
+        assert_eq!(sorted.as_slice(), &[1, 1, 2, 3]);
+        assert_eq!(sorted.into_vec(), vec![1, 1, 2, 3]);
+        assert!(SortedVec::<i32>::new().is_empty());
+    }
+
+    #[test]
+    fn insert() {
+        let mut sorted = SortedVec::new();
+
+        assert_eq!(sorted.insert(20), 0);
+        assert_eq!(sorted.insert(10), 0);
+        assert_eq!(sorted.insert(30), 2);
+        assert_eq!(sorted.insert(20), 2);
+        assert_eq!(sorted.as_slice(), &[10, 20, 20, 30]);
+    }
+
+    #[test]
+    fn collect_and_extend() {
+        let mut sorted: SortedVec<i32> = [5, 3, 4].into_iter().collect();
+        sorted.extend([1, 6]);
+
+        assert_eq!(sorted.as_slice(), &[1, 3, 4, 5, 6]);
+        assert_eq!(Vec::from(SortedVec::from(vec![2, 1])), vec![1, 2]);
+
+        let mut descending = SortedVec::sort_vec_with(vec![1, 3], Descending);
+        descending.extend([2, 3]);
+        assert_eq!(descending.as_slice(), &[3, 3, 2, 1]);
+    }
+
+    #[test]
+    fn try_from_sorted() {
+        let sorted = SortedVec::try_from_sorted(vec![1, 2, 2, 5]).unwrap();
+        assert_eq!(sorted.as_slice(), &[1, 2, 2, 5]);
+
+        let err = SortedVec::try_from_sorted(vec![4, 3]).unwrap_err();
+        assert_eq!(err.index(), 1);
+    }
+
+    #[test]
+    fn remove() {
+        let mut sorted = SortedVec::sort_vec(vec![4, 1, 3, 2, 3]);
+
+        assert_eq!(sorted.remove(&3), Some(3));
+        assert_eq!(sorted.remove(&5), None);
+        assert_eq!(sorted.remove_at(1), 2);
+        assert_eq!(sorted.pop_first(), Some(1));
+        assert_eq!(sorted.pop_last(), Some(4));
+        assert_eq!(sorted.as_slice(), &[3]);
+
+        #[allow(deprecated)]
+        {
+            let mut aliased = SortedVec::sort_vec(vec![2, 1, 3]);
+            assert_eq!(aliased.pop_min(), Some(1));
+            assert_eq!(aliased.pop_max(), Some(3));
+        }
+
+        sorted.clear();
+        assert_eq!(sorted.pop_first(), None);
+        assert_eq!(sorted.pop_last(), None);
+    }
+
+    #[test]
+    fn merge() {
+        let left = SortedVec::sort_vec(vec![1, 3, 3, 7]);
+        let right = SortedVec::sort_vec(vec![2, 3, 8]);
+
+        assert_eq!(
+            left.clone().merge(right.clone()).as_slice(),
+            &[1, 2, 3, 3, 3, 7, 8]
+        );
+        assert_eq!(left.merge_dedup(right).as_slice(), &[1, 2, 3, 7, 8]);
+        assert!(SortedVec::<i32>::new().merge(SortedVec::new()).is_empty());
+    }
+
+    #[test]
+    fn merge_k() {
+        let shards = [
+            SortedVec::sort_vec(vec![5, 1]),
+            SortedVec::sort_vec(vec![3, 2, 9]),
+            SortedVec::sort_vec(vec![4]),
+        ];
+        let merged = SortedVec::merge_k(shards.iter().map(SortedVec::as_sorted_slice));
+
+        assert_eq!(merged.as_slice(), &[1, 2, 3, 4, 5, 9]);
+    }
+
+    #[test]
+    fn descending() {
+        let scores = SortedVec::sort_vec_with(vec![30, 10, 50, 20], Descending);
+
+        assert_eq!(scores.as_slice(), &[50, 30, 20, 10]);
+        assert_eq!(scores.binary_search(&20), Ok(2));
+        let bounds = (Bound::Included(40), Bound::Included(20));
+        assert_eq!(scores.range(bounds).as_slice(), &[30, 20]);
+        assert_eq!(scores.lower_bound(&25), 2);
+
+        let mut popped = scores.clone();
+        assert_eq!(popped.pop_first(), Some(50));
+        assert_eq!(popped.pop_last(), Some(10));
+
+        let ascending = scores.into_ascending();
+        assert_eq!(ascending.as_slice(), &[10, 20, 30, 50]);
+        assert_eq!(ascending.into_descending().as_slice(), &[50, 30, 20, 10]);
+    }
+
+    #[test]
+    fn insert_batch() {
+        let mut sorted = SortedVec::sort_vec(vec![2, 4, 6]);
+        sorted.insert_batch(vec![5, 1, 4]);
+
+        assert_eq!(sorted.as_slice(), &[1, 2, 4, 4, 5, 6]);
+
+        let mut empty = SortedVec::new();
+        empty.insert_batch(vec![3, 1]);
+        assert_eq!(empty.as_slice(), &[1, 3]);
+    }
+
+    #[test]
+    fn from_iter_verify() {
+        let sorted = SortedVec::from_iter_verify([1, 2, 2, 7]).unwrap();
+        assert_eq!(sorted.as_slice(), &[1, 2, 2, 7]);
+
+        let mut consumed = 0;
+        let err = SortedVec::from_iter_verify([1, 5, 3, 4].into_iter().inspect(|_| consumed += 1))
+            .unwrap_err();
+        assert_eq!(err.index(), 2);
+        assert_eq!(consumed, 3);
+
+        // SAFETY: The range is ascending.
+        let trusted = unsafe { SortedVec::from_iter_sorted_unchecked(0..3) };
+        assert_eq!(trusted.as_slice(), &[0, 1, 2]);
+    }
+}
@@ -1,18 +1,39 @@
+#[cfg(feature = "allocator_api")]
+mod alloc;
+mod entry;
+mod iter;
+
 use std::{
-    fmt,
+    borrow::{Borrow, BorrowMut},
+    collections::TryReserveError,
+    mem::ManuallyDrop,
     num::NonZeroUsize,
+    ops::{Add, AddAssign},
+};
+#[cfg(not(feature = "allocator_api"))]
+use std::{
+    fmt,
     ops::{Deref, DerefMut},
 };
 
-use crate::slice::NonEmptyIter;
+use crate::slice::{NonEmptyChunks, NonEmptyIter, NonEmptyIterMut};
 
-use super::slice::NonEmptySlice;
+use super::{slice::NonEmptySlice, NestedPath, Partitioned, SameLenMap};
+pub use entry::EntryMut;
+pub(crate) use error::Empty;
+pub use iter::NonEmptyIntoIter;
 
-#[derive(Clone, PartialEq, Eq)]
+#[cfg(not(feature = "allocator_api"))]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct NonEmptyVec<T> {
     inner: Vec<T>,
 }
 
+#[cfg(feature = "allocator_api")]
+pub struct NonEmptyVec<T, A: std::alloc::Allocator = std::alloc::Global> {
+    inner: Vec<T, A>,
+}
+
 mod error {
     use std::{error::Error, fmt};
 
@@ -39,30 +60,89 @@ impl<T> NonEmptyVec<T> {
         NonEmptyVec { inner }
     }
 
+    pub(crate) unsafe fn new_unchecked(inner: Vec<T>) -> NonEmptyVec<T> {
+        debug_assert!(!inner.is_empty());
+        NonEmptyVec { inner }
+    }
+
+    /// # Safety
+    ///
+    /// The caller must not leave the vector empty.
+    pub(crate) unsafe fn as_mut_vec(&mut self) -> &mut Vec<T> {
+        &mut self.inner
+    }
+
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(
+        iter: I,
+    ) -> Result<NonEmptyVec<T>, error::Empty> {
+        let mut iter = iter.into_iter();
+        let first = iter.next().ok_or(error::Empty)?;
+        let (lower, _) = iter.size_hint();
+        let mut non_empty_vec = NonEmptyVec::with_capacity(first, lower.saturating_add(1));
+        non_empty_vec.extend(iter);
+        Ok(non_empty_vec)
+    }
+
+    /// # Safety
+    ///
+    /// All the safety requirements of [`Vec::from_raw_parts`] apply.
+    /// In addition `len` being non-zero means `ptr` must point to at
+    /// least one initialized element, which is what keeps the resulting
+    /// vector non-empty.
+    pub unsafe fn from_raw_parts(
+        ptr: *mut T,
+        len: NonZeroUsize,
+        capacity: usize,
+    ) -> NonEmptyVec<T> {
+        // SAFETY: The caller upholds the `Vec::from_raw_parts` contract
+        // and `len` is non-zero.
+        let inner = unsafe { Vec::from_raw_parts(ptr, len.get(), capacity) };
+        NonEmptyVec { inner }
+    }
+
+    pub fn into_raw_parts(self) -> (*mut T, NonZeroUsize, usize) {
+        let len = self.non_zero_len();
+        let mut inner = ManuallyDrop::new(self.inner);
+        (inner.as_mut_ptr(), len, inner.capacity())
+    }
+
     pub fn non_zero_len(&self) -> NonZeroUsize {
-        self.inner.len().try_into().unwrap()
+        self.as_non_empty_slice().non_zero_len()
     }
 
     pub fn first(&self) -> &T {
-        &self.inner[0]
+        self.as_non_empty_slice().first()
     }
 
     pub fn tail(&self) -> &[T] {
-        &self.inner[1..]
+        self.as_non_empty_slice().tail()
     }
 
     pub fn last(&self) -> &T {
-        &self.inner[self.len() - 1]
+        self.as_non_empty_slice().last()
     }
 
     pub fn init(&self) -> &[T] {
-        &self.inner[..self.len() - 1]
+        self.as_non_empty_slice().init()
+    }
+
+    pub fn first_entry(&mut self) -> EntryMut<'_, T> {
+        EntryMut::new_unchecked(&mut self.inner, 0)
+    }
+
+    pub fn last_entry(&mut self) -> EntryMut<'_, T> {
+        let index = self.inner.len() - 1;
+        EntryMut::new_unchecked(&mut self.inner, index)
     }
 
     pub fn push(&mut self, value: T) {
         self.inner.push(value)
     }
 
+    pub fn insert(&mut self, index: usize, element: T) {
+        self.inner.insert(index, element)
+    }
+
     pub fn reverse(&mut self) {
         self.inner.reverse()
     }
@@ -75,6 +155,30 @@ impl<T> NonEmptyVec<T> {
         (self.init(), self.last())
     }
 
+    pub fn first_mut(&mut self) -> &mut T {
+        self.as_non_empty_slice_mut().first_mut()
+    }
+
+    pub fn tail_mut(&mut self) -> &mut [T] {
+        self.as_non_empty_slice_mut().tail_mut()
+    }
+
+    pub fn last_mut(&mut self) -> &mut T {
+        self.as_non_empty_slice_mut().last_mut()
+    }
+
+    pub fn init_mut(&mut self) -> &mut [T] {
+        self.as_non_empty_slice_mut().init_mut()
+    }
+
+    pub fn split_first_mut(&mut self) -> (&mut T, &mut [T]) {
+        self.as_non_empty_slice_mut().split_first_mut()
+    }
+
+    pub fn split_last_mut(&mut self) -> (&mut [T], &mut T) {
+        self.as_non_empty_slice_mut().split_last_mut()
+    }
+
     pub fn as_non_empty_slice(&self) -> &NonEmptySlice<T> {
         unsafe { NonEmptySlice::new_unchecked(&self.inner) }
     }
@@ -100,6 +204,88 @@ impl<T> NonEmptyVec<T> {
         unsafe { NonEmptySlice::unchecked_boxed(b) }
     }
 
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional)
+    }
+
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.inner.reserve_exact(additional)
+    }
+
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.inner.try_reserve(additional)
+    }
+
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.inner.try_reserve_exact(additional)
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit()
+    }
+
+    pub fn partition<F: FnMut(&T) -> bool>(self, f: F) -> (Vec<T>, Vec<T>) {
+        self.inner.into_iter().partition(f)
+    }
+
+    pub fn partition_non_empty<F: FnMut(&T) -> bool>(self, f: F) -> Partitioned<T, T> {
+        let (left, right) = self.partition(f);
+        Partitioned::from_vecs(left, right)
+    }
+
+    pub fn chunk_by<F>(self, mut same_group: F) -> NonEmptyVec<NonEmptyVec<T>>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut iter = self.inner.into_iter();
+        // SAFETY: The vector is non-empty.
+        let first = unsafe { iter.next().unwrap_unchecked() };
+        let mut groups = NonEmptyVec::one(NonEmptyVec::one(first));
+        for value in iter {
+            let mut group = groups.last_entry();
+            if same_group(group.get().last(), &value) {
+                group.get_mut().push(value);
+            } else {
+                groups.push(NonEmptyVec::one(value));
+            }
+        }
+        groups
+    }
+
+    pub fn leak<'a>(self) -> &'a mut NonEmptySlice<T> {
+        unsafe { NonEmptySlice::new_unchecked_mut(self.inner.leak()) }
+    }
+
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> NonEmptyVec<U> {
+        self.same_len_map(f)
+    }
+
+    pub fn concat_with(mut self, other: NonEmptyVec<T>) -> NonEmptyVec<T> {
+        self.inner.extend(other.inner);
+        self
+    }
+
+    pub fn reduce<F: FnMut(T, T) -> T>(self, f: F) -> T {
+        let mut iter = self.inner.into_iter();
+        // SAFETY: The vector is non-empty.
+        let first = unsafe { iter.next().unwrap_unchecked() };
+        iter.fold(first, f)
+    }
+
+    pub fn zip<U>(self, other: NonEmptyVec<U>) -> NonEmptyVec<(T, U)> {
+        let zipped = self.inner.into_iter().zip(other.inner).collect();
+        // SAFETY: Zipping two non-empty vectors yields at least one pair.
+        unsafe { NonEmptyVec::new_unchecked(zipped) }
+    }
+
+    pub fn chunks_non_empty(&self, size: NonZeroUsize) -> NonEmptyChunks<'_, T> {
+        self.as_non_empty_slice().chunks(size)
+    }
+
     pub fn truncate(&mut self, len: NonZeroUsize) {
         self.inner.truncate(len.get())
     }
@@ -107,12 +293,94 @@ impl<T> NonEmptyVec<T> {
     pub fn iter(&self) -> NonEmptyIter<'_, T> {
         NonEmptyIter::new_unchecked(self.inner.iter())
     }
+
+    pub fn iter_mut(&mut self) -> NonEmptyIterMut<'_, T> {
+        NonEmptyIterMut::new_unchecked(self.inner.iter_mut())
+    }
+
+    pub fn non_empty_into_iter(self) -> NonEmptyIntoIter<T> {
+        NonEmptyIntoIter::new_unchecked(self.inner.into_iter())
+    }
+}
+
+impl<T, const N: usize> NonEmptyVec<[T; N]> {
+    pub fn into_flattened(self) -> NonEmptyVec<T> {
+        const {
+            assert!(
+                N > 0,
+                "cannot flatten arrays of length zero into a NonEmptyVec"
+            )
+        };
+        // SAFETY: There is at least one array, and every array holds
+        // at least one element.
+        unsafe { NonEmptyVec::new_unchecked(self.inner.into_flattened()) }
+    }
+}
+
+impl<A, B> NonEmptyVec<(A, B)> {
+    pub fn unzip(self) -> (NonEmptyVec<A>, NonEmptyVec<B>) {
+        let (left, right) = self.inner.into_iter().unzip();
+        // SAFETY: Each pair contributes one element to both sides,
+        // so both have the same non-zero length as `self`.
+        unsafe {
+            (
+                NonEmptyVec::new_unchecked(left),
+                NonEmptyVec::new_unchecked(right),
+            )
+        }
+    }
+}
+
+impl<T> NonEmptyVec<NonEmptyVec<T>> {
+    pub fn get_path<L, D>(&self, path: &NonEmptySlice<usize>) -> Option<&L>
+    where
+        Self: NestedPath<L, D>,
+    {
+        self.walk(path.as_slice())
+    }
+
+    pub fn get_path_mut<L, D>(&mut self, path: &NonEmptySlice<usize>) -> Option<&mut L>
+    where
+        Self: NestedPath<L, D>,
+    {
+        self.walk_mut(path.as_slice())
+    }
 }
 
 impl<T: PartialEq> NonEmptyVec<T> {
     pub fn dedup(&mut self) {
         self.inner.dedup();
     }
+
+    pub fn dedup_with_counts(self) -> NonEmptyVec<(T, NonZeroUsize)> {
+        let mut iter = self.inner.into_iter();
+        // SAFETY: The vector is non-empty.
+        let first = unsafe { iter.next().unwrap_unchecked() };
+        let mut runs = NonEmptyVec::one((first, NonZeroUsize::MIN));
+        for value in iter {
+            let (last, count) = runs.last_mut();
+            if *last == value {
+                *count = count.saturating_add(1);
+            } else {
+                runs.push((value, NonZeroUsize::MIN));
+            }
+        }
+        runs
+    }
+}
+
+impl<T: Ord> NonEmptyVec<T> {
+    pub fn max_elem(&self) -> &T {
+        self.as_non_empty_slice().max()
+    }
+
+    pub fn min_elem(&self) -> &T {
+        self.as_non_empty_slice().min()
+    }
+
+    pub fn minmax(&self) -> (&T, &T) {
+        self.as_non_empty_slice().minmax()
+    }
 }
 
 impl<T: Clone> NonEmptyVec<T> {
@@ -135,6 +403,12 @@ impl<T: Clone> NonEmptyVec<T> {
     }
 }
 
+impl<T: Copy> NonEmptyVec<T> {
+    pub fn repeat(&self, n: NonZeroUsize) -> NonEmptyVec<T> {
+        self.as_non_empty_slice().repeat_non_zero(n)
+    }
+}
+
 impl<'a, T> Extend<&'a T> for NonEmptyVec<T>
 where
     T: 'a + Copy,
@@ -144,18 +418,95 @@ where
     }
 }
 
+#[cfg(not(feature = "allocator_api"))]
 impl<T> Extend<T> for NonEmptyVec<T> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         self.inner.extend(iter);
     }
 }
 
+impl<T> Add for NonEmptyVec<T> {
+    type Output = NonEmptyVec<T>;
+
+    fn add(self, other: NonEmptyVec<T>) -> Self::Output {
+        self.concat_with(other)
+    }
+}
+
+impl<T> AddAssign for NonEmptyVec<T> {
+    fn add_assign(&mut self, other: NonEmptyVec<T>) {
+        self.inner.extend(other.inner);
+    }
+}
+
 impl<T: Clone> From<&NonEmptySlice<T>> for NonEmptyVec<T> {
     fn from(slice: &NonEmptySlice<T>) -> Self {
         slice.to_non_empty_vec()
     }
 }
 
+impl<T> From<NonEmptyVec<T>> for Box<NonEmptySlice<T>> {
+    fn from(vec: NonEmptyVec<T>) -> Self {
+        vec.into_boxed_slice()
+    }
+}
+
+impl<T> From<Box<NonEmptySlice<T>>> for NonEmptyVec<T> {
+    fn from(boxed: Box<NonEmptySlice<T>>) -> Self {
+        boxed.into_non_empty_vec()
+    }
+}
+
+impl<T> From<Box<NonEmptySlice<T>>> for Vec<T> {
+    fn from(boxed: Box<NonEmptySlice<T>>) -> Self {
+        boxed.into_boxed_slice().into_vec()
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl<T> From<NonEmptyVec<T>> for Vec<T> {
+    fn from(vec: NonEmptyVec<T>) -> Self {
+        vec.into_vec()
+    }
+}
+
+impl<T> Borrow<NonEmptySlice<T>> for NonEmptyVec<T> {
+    fn borrow(&self) -> &NonEmptySlice<T> {
+        self.as_non_empty_slice()
+    }
+}
+
+impl<T> BorrowMut<NonEmptySlice<T>> for NonEmptyVec<T> {
+    fn borrow_mut(&mut self) -> &mut NonEmptySlice<T> {
+        self.as_non_empty_slice_mut()
+    }
+}
+
+impl<T> AsRef<NonEmptySlice<T>> for NonEmptyVec<T> {
+    fn as_ref(&self) -> &NonEmptySlice<T> {
+        self.as_non_empty_slice()
+    }
+}
+
+impl<T> AsRef<[T]> for NonEmptyVec<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> AsMut<NonEmptySlice<T>> for NonEmptyVec<T> {
+    fn as_mut(&mut self) -> &mut NonEmptySlice<T> {
+        self.as_non_empty_slice_mut()
+    }
+}
+
+impl<T> AsMut<[T]> for NonEmptyVec<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.inner
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
 impl<T> TryFrom<Vec<T>> for NonEmptyVec<T> {
     type Error = error::Empty;
 
@@ -168,6 +519,7 @@ impl<T> TryFrom<Vec<T>> for NonEmptyVec<T> {
     }
 }
 
+#[cfg(not(feature = "allocator_api"))]
 impl<T: fmt::Debug> fmt::Debug for NonEmptyVec<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(&*self.inner, f)
@@ -192,6 +544,16 @@ impl<'a, T> IntoIterator for &'a NonEmptyVec<T> {
     }
 }
 
+impl<'a, T> IntoIterator for &'a mut NonEmptyVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter_mut()
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
 impl<T> Deref for NonEmptyVec<T> {
     type Target = NonEmptySlice<T>;
 
@@ -201,6 +563,7 @@ impl<T> Deref for NonEmptyVec<T> {
     }
 }
 
+#[cfg(not(feature = "allocator_api"))]
 impl<T> DerefMut for NonEmptyVec<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.as_non_empty_slice_mut()
@@ -298,6 +661,24 @@ mod tests {
         assert_eq!(multiple.split_last(), (&[10, 20, 30, 40][..], &50));
     }
 
+    #[test]
+    fn split_mut() {
+        let mut one = non_empty_vec![10];
+
+        *one.first_mut() += 1;
+        *one.last_mut() += 1;
+        assert!(one.tail_mut().is_empty());
+        assert!(one.init_mut().is_empty());
+        assert_eq!(one, non_empty_vec![12]);
+
+        let mut multiple = non_empty_vec![10, 20, 30];
+        let (first, tail) = multiple.split_first_mut();
+        tail[0] = *first;
+        let (init, last) = multiple.split_last_mut();
+        *last = init[1];
+        assert_eq!(multiple, non_empty_vec![10, 10, 10]);
+    }
+
     #[test]
     fn extend_from_slice() {
         let mut one = non_empty_vec![10];
@@ -359,4 +740,247 @@ mod tests {
         v.dedup();
         assert_eq!(v, non_empty_vec![1, 2, 1]);
     }
+
+    #[test]
+    fn repeat() {
+        let v = non_empty_vec![1, 2];
+        assert_eq!(v.repeat(NonZeroUsize::new(1).unwrap()), non_empty_vec![1, 2]);
+        assert_eq!(v.repeat(NonZeroUsize::new(2).unwrap()), non_empty_vec![1, 2, 1, 2]);
+    }
+
+    #[test]
+    fn try_from_iter() {
+        let v = NonEmptyVec::try_from_iter(1..=3).unwrap();
+        assert_eq!(v, non_empty_vec![1, 2, 3]);
+
+        let v = NonEmptyVec::try_from_iter(std::iter::once("a")).unwrap();
+        assert_eq!(v, non_empty_vec!["a"]);
+
+        assert!(NonEmptyVec::<i32>::try_from_iter(std::iter::empty()).is_err());
+    }
+
+    #[test]
+    fn capacity() {
+        let mut v = NonEmptyVec::one(1);
+
+        v.reserve(10);
+        assert!(v.capacity() >= 11);
+
+        v.reserve_exact(20);
+        assert!(v.capacity() >= 21);
+
+        assert!(v.try_reserve(5).is_ok());
+        assert!(v.try_reserve(usize::MAX).is_err());
+
+        v.shrink_to_fit();
+        assert!(v.capacity() >= 1);
+        assert_eq!(v, non_empty_vec![1]);
+    }
+
+    #[test]
+    fn raw_parts() {
+        let v = non_empty_vec![String::from("a"), String::from("b")];
+
+        let (ptr, len, capacity) = v.into_raw_parts();
+        assert_eq!(len.get(), 2);
+
+        // SAFETY: The parts come straight from `into_raw_parts`.
+        let v = unsafe { NonEmptyVec::from_raw_parts(ptr, len, capacity) };
+        assert_eq!(v, non_empty_vec![String::from("a"), String::from("b")]);
+    }
+
+    #[test]
+    fn partition() {
+        let v = non_empty_vec![1, 2, 3, 4, 5];
+        let (even, odd) = v.clone().partition(|v| v % 2 == 0);
+        assert_eq!(even, vec![2, 4]);
+        assert_eq!(odd, vec![1, 3, 5]);
+
+        assert_eq!(
+            v.clone().partition_non_empty(|v| v % 2 == 0),
+            Partitioned::Both(non_empty_vec![2, 4], non_empty_vec![1, 3, 5])
+        );
+        assert_eq!(
+            v.clone().partition_non_empty(|&v| v > 0),
+            Partitioned::Left(non_empty_vec![1, 2, 3, 4, 5])
+        );
+        assert_eq!(
+            v.partition_non_empty(|&v| v > 5),
+            Partitioned::Right(non_empty_vec![1, 2, 3, 4, 5])
+        );
+    }
+
+    #[test]
+    fn chunk_by() {
+        let v = non_empty_vec![1, 1, 2, 3, 3, 3, 1];
+        assert_eq!(
+            v.chunk_by(|a, b| a == b),
+            non_empty_vec![
+                non_empty_vec![1, 1],
+                non_empty_vec![2],
+                non_empty_vec![3, 3, 3],
+                non_empty_vec![1]
+            ]
+        );
+
+        let v = non_empty_vec![1, 2, 3, 2, 3];
+        assert_eq!(
+            v.chunk_by(|a, b| a < b),
+            non_empty_vec![non_empty_vec![1, 2, 3], non_empty_vec![2, 3]]
+        );
+
+        assert_eq!(
+            non_empty_vec![1].chunk_by(|_, _| false),
+            non_empty_vec![non_empty_vec![1]]
+        );
+    }
+
+    #[test]
+    fn leak() {
+        let table: &'static mut NonEmptySlice<i32> = non_empty_vec![1, 2, 3].leak();
+        table.reverse();
+
+        let table: &'static NonEmptySlice<i32> = table;
+        assert_eq!(table.as_slice(), &[3, 2, 1]);
+    }
+
+    #[test]
+    fn min_max() {
+        let v = non_empty_vec![3, 1, 2];
+        assert_eq!(v.max_elem(), &3);
+        assert_eq!(v.min_elem(), &1);
+        assert_eq!(v.minmax(), (&1, &3));
+
+        let slice = &*v;
+        assert_eq!(slice.max(), &3);
+        assert_eq!(slice.min(), &1);
+    }
+
+    #[test]
+    fn map_zip_unzip() {
+        let v = non_empty_vec![1, 2, 3];
+
+        let mapped = v.clone().map(|v| v * 10);
+        assert_eq!(mapped, non_empty_vec![10, 20, 30]);
+
+        let zipped = v.zip(non_empty_vec!["a", "b"]);
+        assert_eq!(zipped, non_empty_vec![(1, "a"), (2, "b")]);
+
+        assert_eq!(
+            zipped.unzip(),
+            (non_empty_vec![1, 2], non_empty_vec!["a", "b"])
+        );
+    }
+
+    #[test]
+    fn reduce() {
+        assert_eq!(non_empty_vec![1, 2, 3, 4].reduce(|a, b| a + b), 10);
+        assert_eq!(non_empty_vec![5].reduce(|a, b| a * b), 5);
+        assert_eq!(
+            non_empty_vec![String::from("a"), String::from("b")].reduce(|a, b| a + &b),
+            "ab"
+        );
+    }
+
+    #[test]
+    fn select_nth_unstable() {
+        let mut v = non_empty_vec![3, 1, 2];
+        let (_, median, _) = v.select_nth_unstable(1);
+        assert_eq!(*median, 2);
+    }
+
+    #[test]
+    fn dedup_with_counts() {
+        let count = |n| NonZeroUsize::new(n).unwrap();
+
+        let v = non_empty_vec!['a', 'a', 'b', 'a', 'a', 'a'];
+        assert_eq!(
+            v.dedup_with_counts(),
+            non_empty_vec![('a', count(2)), ('b', count(1)), ('a', count(3))]
+        );
+
+        let v = non_empty_vec![1];
+        assert_eq!(v.dedup_with_counts(), non_empty_vec![(1, count(1))]);
+    }
+
+    #[test]
+    fn concat() {
+        assert_eq!(
+            non_empty_vec![1] + non_empty_vec![2, 3],
+            non_empty_vec![1, 2, 3]
+        );
+        assert_eq!(
+            non_empty_vec![1, 2].concat_with(non_empty_vec![3]),
+            non_empty_vec![1, 2, 3]
+        );
+
+        let mut v = non_empty_vec![1];
+        v += non_empty_vec![2];
+        v += non_empty_vec![3, 4];
+        assert_eq!(v, non_empty_vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn into_flattened() {
+        let rows = non_empty_vec![[1, 2, 3], [4, 5, 6]];
+        assert_eq!(rows.into_flattened(), non_empty_vec![1, 2, 3, 4, 5, 6]);
+
+        let rows = non_empty_vec![[1]];
+        assert_eq!(rows.into_flattened(), non_empty_vec![1]);
+    }
+
+    #[test]
+    fn chunks_non_empty() {
+        let v = non_empty_vec![1, 2, 3];
+        let lens: Vec<usize> = v
+            .chunks_non_empty(NonZeroUsize::new(2).unwrap())
+            .map(|chunk| chunk.non_zero_len().get())
+            .collect();
+        assert_eq!(lens, vec![2, 1]);
+    }
+
+    #[test]
+    fn insert_at_search_position() {
+        let mut v = non_empty_vec![10, 20, 30];
+
+        for value in [25, 5, 35, 20] {
+            let index = v.binary_search(&value).unwrap_or_else(|index| index);
+            v.insert(index, value);
+        }
+
+        assert_eq!(v, non_empty_vec![5, 10, 20, 20, 25, 30, 35]);
+    }
+
+    #[test]
+    fn borrow_and_as_ref() {
+        fn borrowed<B: Borrow<NonEmptySlice<i32>>>(value: &B) -> i32 {
+            *value.borrow().first()
+        }
+
+        fn sum<S: AsRef<[i32]>>(value: S) -> i32 {
+            value.as_ref().iter().sum()
+        }
+
+        let mut vec = non_empty_vec![1, 2, 3];
+
+        assert_eq!(borrowed(&vec), 1);
+        assert_eq!(sum(&vec), 6);
+
+        AsMut::<[i32]>::as_mut(&mut vec).reverse();
+        *AsMut::<NonEmptySlice<i32>>::as_mut(&mut vec).first_mut() = 10;
+
+        assert_eq!(vec, non_empty_vec![10, 2, 1]);
+    }
+
+    #[test]
+    fn boxed_round_trip() {
+        let boxed: Box<NonEmptySlice<i32>> = non_empty_vec![1, 2, 3].into();
+
+        let vec = NonEmptyVec::from(boxed.clone());
+        assert_eq!(vec, [1, 2, 3]);
+
+        let plain: Vec<i32> = boxed.into();
+        assert_eq!(plain, vec![1, 2, 3]);
+        assert_eq!(Vec::from(vec), plain);
+    }
 }
@@ -0,0 +1,156 @@
+use std::{
+    alloc::Allocator,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+};
+
+use super::{error, NonEmptyVec};
+use crate::NonEmptySlice;
+
+impl<T, A: Allocator> NonEmptyVec<T, A> {
+    pub fn new_in(first: T, alloc: A) -> NonEmptyVec<T, A> {
+        NonEmptyVec::with_capacity_in(first, 1, alloc)
+    }
+
+    pub fn with_capacity_in(first: T, capacity: usize, alloc: A) -> NonEmptyVec<T, A> {
+        let mut inner = Vec::with_capacity_in(capacity, alloc);
+        inner.push(first);
+        NonEmptyVec { inner }
+    }
+
+    pub fn allocator(&self) -> &A {
+        self.inner.allocator()
+    }
+}
+
+impl<T, A: Allocator> TryFrom<Vec<T, A>> for NonEmptyVec<T, A> {
+    type Error = error::Empty;
+
+    fn try_from(vec: Vec<T, A>) -> Result<Self, Self::Error> {
+        if vec.is_empty() {
+            Err(error::Empty)
+        } else {
+            Ok(NonEmptyVec { inner: vec })
+        }
+    }
+}
+
+impl<T, A: Allocator> From<NonEmptyVec<T, A>> for Vec<T, A> {
+    fn from(vec: NonEmptyVec<T, A>) -> Self {
+        vec.inner
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for NonEmptyVec<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter);
+    }
+}
+
+impl<T, A: Allocator> Deref for NonEmptyVec<T, A> {
+    type Target = NonEmptySlice<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: The vector is non-empty.
+        unsafe { NonEmptySlice::new_unchecked(&self.inner) }
+    }
+}
+
+impl<T, A: Allocator> DerefMut for NonEmptyVec<T, A> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: The vector is non-empty.
+        unsafe { NonEmptySlice::new_unchecked_mut(&mut self.inner) }
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for NonEmptyVec<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.inner, f)
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for NonEmptyVec<T, A> {
+    fn clone(&self) -> Self {
+        NonEmptyVec {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T, U, A1, A2> PartialEq<NonEmptyVec<U, A2>> for NonEmptyVec<T, A1>
+where
+    T: PartialEq<U>,
+    A1: Allocator,
+    A2: Allocator,
+{
+    fn eq(&self, other: &NonEmptyVec<U, A2>) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for NonEmptyVec<T, A> {}
+
+impl<T: PartialOrd, A1: Allocator, A2: Allocator> PartialOrd<NonEmptyVec<T, A2>>
+    for NonEmptyVec<T, A1>
+{
+    fn partial_cmp(&self, other: &NonEmptyVec<T, A2>) -> Option<Ordering> {
+        self.inner.partial_cmp(&other.inner)
+    }
+}
+
+impl<T: Ord, A: Allocator> Ord for NonEmptyVec<T, A> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl<T: Hash, A: Allocator> Hash for NonEmptyVec<T, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::{alloc::System, collections::HashSet};
+
+    use super::*;
+    use crate::non_empty_vec;
+
+    #[test]
+    fn custom_allocator() {
+        let mut vec = NonEmptyVec::new_in(1, System);
+        vec.extend([2, 3]);
+        assert_eq!(vec.first(), &1);
+        assert_eq!(vec.non_zero_len().get(), 3);
+        assert_eq!(vec, non_empty_vec![1, 2, 3]);
+        assert_eq!(format!("{vec:?}"), "[1, 2, 3]");
+
+        let copy = vec.clone();
+        assert_eq!(copy.cmp(&vec), Ordering::Equal);
+        let _: &System = copy.allocator();
+
+        let vec = Vec::from(vec);
+        assert_eq!(vec, [1, 2, 3]);
+    }
+
+    #[test]
+    fn conversions_in() {
+        let vec = NonEmptyVec::with_capacity_in("a", 4, System);
+        assert!(vec.inner.capacity() >= 4);
+
+        assert!(NonEmptyVec::try_from(Vec::<i32, _>::new_in(System)).is_err());
+
+        let mut inner = Vec::new_in(System);
+        inner.push(5);
+        let vec = NonEmptyVec::try_from(inner).unwrap();
+        assert_eq!(vec.last(), &5);
+
+        let set: HashSet<_> = [non_empty_vec![1], non_empty_vec![1]].into_iter().collect();
+        assert_eq!(set.len(), 1);
+    }
+}
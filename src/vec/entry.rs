@@ -0,0 +1,72 @@
+use std::mem;
+
+pub struct EntryMut<'a, T> {
+    vec: &'a mut Vec<T>,
+    index: usize,
+}
+
+impl<'a, T> EntryMut<'a, T> {
+    pub(super) fn new_unchecked(vec: &'a mut Vec<T>, index: usize) -> Self {
+        debug_assert!(index < vec.len());
+        EntryMut { vec, index }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn get(&self) -> &T {
+        // SAFETY: `index` was in bounds on creation and the vector
+        // cannot be resized while we hold the mutable borrow.
+        unsafe { self.vec.get_unchecked(self.index) }
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        // SAFETY: See `get`.
+        unsafe { self.vec.get_unchecked_mut(self.index) }
+    }
+
+    pub fn into_mut(self) -> &'a mut T {
+        // SAFETY: See `get`.
+        unsafe { self.vec.get_unchecked_mut(self.index) }
+    }
+
+    pub fn replace(&mut self, value: T) -> T {
+        mem::replace(self.get_mut(), value)
+    }
+
+    pub fn map_in_place<F: FnOnce(&mut T)>(&mut self, f: F) {
+        f(self.get_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::non_empty_vec;
+
+    #[test]
+    fn first_entry() {
+        let mut vec = non_empty_vec![10, 20, 30];
+
+        let mut entry = vec.first_entry();
+        assert_eq!(entry.index(), 0);
+        assert_eq!(entry.get(), &10);
+        assert_eq!(entry.replace(11), 10);
+        entry.map_in_place(|v| *v *= 2);
+
+        assert_eq!(vec, non_empty_vec![22, 20, 30]);
+    }
+
+    #[test]
+    fn last_entry() {
+        let mut vec = non_empty_vec![10, 20, 30];
+
+        let mut entry = vec.last_entry();
+        assert_eq!(entry.index(), 2);
+        assert_eq!(entry.replace(31), 30);
+        *entry.into_mut() += 1;
+
+        assert_eq!(vec, non_empty_vec![10, 20, 32]);
+    }
+}
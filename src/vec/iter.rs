@@ -0,0 +1,62 @@
+use std::{ops::Deref, vec::IntoIter};
+
+use crate::{iter::non_empty_iterator_methods, NonEmptyIterator};
+
+pub struct NonEmptyIntoIter<T>(IntoIter<T>);
+
+impl<T> NonEmptyIntoIter<T> {
+    pub(crate) fn new_unchecked(iter: IntoIter<T>) -> Self {
+        debug_assert!(iter.len() > 0);
+        NonEmptyIntoIter(iter)
+    }
+
+    non_empty_iterator_methods!();
+}
+
+impl<T: Clone> Clone for NonEmptyIntoIter<T> {
+    fn clone(&self) -> Self {
+        NonEmptyIntoIter(self.0.clone())
+    }
+}
+
+impl<T> IntoIterator for NonEmptyIntoIter<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+impl<T> NonEmptyIterator for NonEmptyIntoIter<T> {}
+
+impl<T> Deref for NonEmptyIntoIter<T> {
+    type Target = IntoIter<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{non_empty_vec, NonEmptyVec};
+
+    #[test]
+    fn non_empty_into_iter() {
+        let vec = non_empty_vec!["a".to_string(), "b".to_string()];
+
+        let iter = vec.clone().non_empty_into_iter();
+        assert_eq!(iter.as_slice(), &["a", "b"]);
+
+        let (first, rest) = iter.next_first();
+        assert_eq!(first, "a");
+        assert_eq!(rest.len(), 1);
+
+        let lengths = vec
+            .non_empty_into_iter()
+            .map(|s| s.len())
+            .collect_non_empty::<NonEmptyVec<_>>();
+        assert_eq!(lengths, non_empty_vec![1, 1]);
+    }
+}